@@ -0,0 +1,37 @@
+// Compares the arithmetic speed of BigNum and PackedBigNum over a million values.
+// Run with `cargo bench --bench packed_arithmetic`.
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use bignum::bignum::{BigNum, PackedBigNum};
+
+const COUNT: usize = 1_000_000;
+const RUNS: usize = 10;
+
+// The fastest of several runs, which is the least disturbed by everything else on the machine
+fn fastest<T>(mut run: impl FnMut() -> T) -> (Duration, T) {
+    let mut best = Duration::MAX;
+    let mut result = run();
+
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        result = black_box(run());
+        best = best.min(start.elapsed());
+    }
+
+    return (best, result);
+}
+
+fn main() {
+    let values: Vec<BigNum> = (1..=COUNT).map(|i| BigNum::from_f64(i as f64 * 1.5)).collect();
+    let packed: Vec<PackedBigNum> = values.iter().map(|&value| PackedBigNum::from(value)).collect();
+
+    let (unpacked_time, total) = fastest(|| black_box(&values).iter().fold(BigNum::ZERO, |total, &value| total + value * value));
+    let (packed_time, packed_total) = fastest(|| black_box(&packed).iter().fold(PackedBigNum::ZERO, |total, &value| total + value * value));
+
+    println!("BigNum:       {:>10.2?} ({} bytes per value)", unpacked_time, std::mem::size_of::<BigNum>());
+    println!("PackedBigNum: {:>10.2?} ({} bytes per value)", packed_time, std::mem::size_of::<PackedBigNum>());
+    println!("PackedBigNum takes {:.2}x as long as BigNum", packed_time.as_secs_f64() / unpacked_time.as_secs_f64());
+
+    assert!((total.exponent - packed_total.exponent()).abs() < 1e-9, "Both totals should match: {} and {}", total, packed_total);
+}
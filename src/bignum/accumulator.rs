@@ -0,0 +1,136 @@
+mod accumulate {
+    use std::ops;
+    use crate::bignum::{BigAccumulator, BigNum};
+
+    // Amounts this many orders of magnitude below the value are buffered instead of added right away
+    const SIGNIFICANT_GAP: f64 = 8.0;
+
+    // Past this, a buffered amount would overflow the f64 register
+    const MAX_SHIFT: f64 = 300.0;
+
+    impl BigAccumulator {
+        pub fn new(value: BigNum) -> Self {
+            return BigAccumulator {
+                value,
+                pending: 0.0,
+                compensation: 0.0,
+                scale: 0.0
+            };
+        }
+
+        /// Adds the amount, buffering it if it's too small to change the value on its own.
+        pub fn add(&mut self, amount: BigNum) {
+            if amount.is_zero() {
+                return;
+            }
+
+            if !amount.is_finite() || !self.value.is_finite() || amount.exponent > self.value.exponent - SIGNIFICANT_GAP {
+                self.flush();
+                self.value += amount;
+                return;
+            }
+
+            if self.pending == 0.0 && self.compensation == 0.0 {
+                self.scale = amount.exponent.floor();
+            } else if amount.exponent - self.scale > MAX_SHIFT {
+                self.flush();
+
+                // What the value couldn't hold is moved to the new scale, where it's far too small to matter
+                let scale = amount.exponent.floor();
+                self.pending *= 10_f64.powf(self.scale - scale);
+                self.scale = scale;
+            }
+
+            let term = 10_f64.powf(amount.exponent - self.scale);
+            let term = if amount.is_negative { -term } else { term };
+
+            // Neumaier's compensated summation, so the buffer doesn't drift either
+            let total = self.pending + term;
+
+            if self.pending.abs() >= term.abs() {
+                self.compensation += (self.pending - total) + term;
+            } else {
+                self.compensation += (term - total) + self.pending;
+            }
+
+            self.pending = total;
+
+            if self.pending().exponent > self.value.exponent - SIGNIFICANT_GAP {
+                self.flush();
+            }
+        }
+
+        /// The value, including the buffered amounts.
+        pub fn value(&self) -> BigNum {
+            return self.value + self.pending();
+        }
+
+        /// Adds the buffered amounts to the value, keeping whatever is too small to change it buffered.
+        pub fn flush(&mut self) {
+            let old = self.value;
+            let new = self.value();
+            self.value = new;
+
+            if !old.is_finite() || !new.is_finite() {
+                self.pending = 0.0;
+                self.compensation = 0.0;
+                return;
+            }
+
+            // Only the part that actually changed the value leaves the buffer, in units of 10^scale
+            let difference = new - old;
+            let applied = if difference.is_zero() { 0.0 } else { 10_f64.powf(difference.exponent - self.scale) };
+            let applied = if difference.is_negative { -applied } else { applied };
+
+            self.pending = (self.pending - applied) + self.compensation;
+            self.compensation = 0.0;
+        }
+
+        /// The buffered amounts that haven't been added to the value yet.
+        pub fn pending(&self) -> BigNum {
+            let total = self.pending + self.compensation;
+
+            // pending is in units of 10^scale
+            return BigNum {
+                is_negative: total < 0.0,
+                exponent: total.abs().log10() + self.scale
+            };
+        }
+    }
+
+    impl Default for BigAccumulator {
+        fn default() -> Self {
+            return BigAccumulator::new(BigNum::ZERO);
+        }
+    }
+
+    impl From<BigNum> for BigAccumulator {
+        fn from(value: BigNum) -> Self {
+            return BigAccumulator::new(value);
+        }
+    }
+
+    impl ops::AddAssign<BigNum> for BigAccumulator {
+        fn add_assign(&mut self, other: BigNum) {
+            self.add(other);
+        }
+    }
+
+    impl ops::AddAssign<f64> for BigAccumulator {
+        fn add_assign(&mut self, other: f64) {
+            self.add(BigNum::from_f64(other));
+        }
+    }
+
+    impl ops::SubAssign<BigNum> for BigAccumulator {
+        fn sub_assign(&mut self, other: BigNum) {
+            self.add(-other);
+        }
+    }
+
+    impl ops::SubAssign<f64> for BigAccumulator {
+        fn sub_assign(&mut self, other: f64) {
+            self.add(BigNum::from_f64(-other));
+        }
+    }
+}
\ No newline at end of file
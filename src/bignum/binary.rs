@@ -0,0 +1,117 @@
+mod bytes {
+    use crate::bignum::{BigNum, BinaryDecodeError};
+
+    // Start of every encoded slice
+    const MAGIC: [u8; 4] = *b"BGNM";
+
+    // magic + version + u64 count
+    const HEADER_LENGTH: usize = MAGIC.len() + 1 + 8;
+
+    const VALUE_LENGTH: usize = 9;
+
+    impl BigNum {
+        /// Version of the format written by `encode_slice`, bumped whenever the encoding changes.
+        pub const BINARY_VERSION: u8 = 1;
+
+        /// Encodes the number as the exponent's little-endian bytes followed by a sign byte (0 or 1).
+        pub fn to_le_bytes(&self) -> [u8; 9] {
+            let mut bytes = [0; VALUE_LENGTH];
+
+            bytes[..8].copy_from_slice(&self.exponent.to_le_bytes());
+            bytes[8] = self.is_negative as u8;
+
+            return bytes;
+        }
+
+        /// Encodes the number as a sign byte (0 or 1) followed by the exponent's big-endian bytes.
+        pub fn to_be_bytes(&self) -> [u8; 9] {
+            let mut bytes = self.to_le_bytes();
+            bytes.reverse();
+
+            return bytes;
+        }
+
+        pub fn from_le_bytes(bytes: [u8; 9]) -> Result<BigNum, BinaryDecodeError> {
+            let is_negative = match bytes[8] {
+                0 => false,
+                1 => true,
+                value => return Err(BinaryDecodeError::InvalidSign { position: 8, value })
+            };
+
+            return Ok(BigNum {
+                is_negative,
+                exponent: f64::from_le_bytes(bytes[..8].try_into().unwrap())
+            });
+        }
+
+        pub fn from_be_bytes(mut bytes: [u8; 9]) -> Result<BigNum, BinaryDecodeError> {
+            bytes.reverse();
+
+            return BigNum::from_le_bytes(bytes).map_err(|error| match error {
+                BinaryDecodeError::InvalidSign { value, .. } => BinaryDecodeError::InvalidSign { position: 0, value },
+                error => error
+            });
+        }
+
+        /// Encodes a slice of numbers, behind a header of the magic bytes 'BGNM',
+        /// the format version and the number of values as a little-endian u64.
+        /// Each value is then encoded with `to_le_bytes`.
+        pub fn encode_slice(values: &[BigNum]) -> Vec<u8> {
+            let mut bytes = Vec::with_capacity(HEADER_LENGTH + values.len() * VALUE_LENGTH);
+
+            bytes.extend_from_slice(&MAGIC);
+            bytes.push(BigNum::BINARY_VERSION);
+            bytes.extend_from_slice(&(values.len() as u64).to_le_bytes());
+
+            for value in values {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+
+            return bytes;
+        }
+
+        /// Decodes numbers written by `encode_slice`, checking the header and every value.
+        pub fn decode_slice(bytes: &[u8]) -> Result<Vec<BigNum>, BinaryDecodeError> {
+            if bytes.len() < MAGIC.len() || bytes[..MAGIC.len()] != MAGIC {
+                return Err(BinaryDecodeError::InvalidMagic);
+            }
+
+            if bytes.len() < HEADER_LENGTH {
+                return Err(BinaryDecodeError::UnexpectedEnd { position: bytes.len() });
+            }
+
+            let version = bytes[MAGIC.len()];
+            if version != BigNum::BINARY_VERSION {
+                return Err(BinaryDecodeError::UnsupportedVersion { version });
+            }
+
+            let count = u64::from_le_bytes(bytes[(MAGIC.len() + 1)..HEADER_LENGTH].try_into().unwrap());
+
+            // The count comes from the input, so it's checked against the length before allocating
+            let end = usize::try_from(count).ok()
+                .and_then(|count| count.checked_mul(VALUE_LENGTH))
+                .and_then(|length| length.checked_add(HEADER_LENGTH));
+
+            match end {
+                Some(end) if end < bytes.len() => return Err(BinaryDecodeError::TrailingBytes { position: end }),
+                Some(end) if end == bytes.len() => {},
+                _ => return Err(BinaryDecodeError::UnexpectedEnd { position: bytes.len() })
+            }
+
+            let mut values = Vec::with_capacity(count as usize);
+
+            for (index, chunk) in bytes[HEADER_LENGTH..].chunks_exact(VALUE_LENGTH).enumerate() {
+                let position = HEADER_LENGTH + index * VALUE_LENGTH;
+
+                let value = BigNum::from_le_bytes(chunk.try_into().unwrap()).map_err(|error| match error {
+                    BinaryDecodeError::InvalidSign { position: offset, value } => BinaryDecodeError::InvalidSign { position: position + offset, value },
+                    error => error
+                })?;
+
+                values.push(value);
+            }
+
+            return Ok(values);
+        }
+    }
+}
\ No newline at end of file
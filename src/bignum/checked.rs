@@ -0,0 +1,161 @@
+mod check {
+    use crate::bignum::{BigNum, BigNumError};
+
+    // Past 2^53, every f64 is an integer
+    const INTEGER_LIMIT_LOG10: f64 = 15.954589770191003;
+
+    fn check_operands(operands: &[BigNum]) -> Result<(), BigNumError> {
+        if operands.iter().any(|operand| operand.is_nan()) {
+            return Err(BigNumError::NanOperand);
+        }
+
+        return Ok(());
+    }
+
+    // Turns a result that became infinite or zero from finite, non-zero operands into an error
+    fn check_range(result: BigNum, operands: &[BigNum]) -> Result<BigNum, BigNumError> {
+        if result.is_nan() {
+            return Err(BigNumError::Indeterminate);
+        }
+
+        let all_finite = operands.iter().all(|operand| operand.is_finite());
+
+        if result.is_infinite() && all_finite {
+            return Err(BigNumError::Overflow);
+        }
+
+        if result.is_zero() && all_finite && !operands.iter().any(|operand| operand.is_zero()) {
+            return Err(BigNumError::Underflow);
+        }
+
+        return Ok(result);
+    }
+
+    fn is_integer(value: &BigNum) -> bool {
+        if value.exponent >= INTEGER_LIMIT_LOG10 || value.is_zero() {
+            return true;
+        }
+
+        return match value.to_f64() {
+            Some(value) => value.fract() == 0.0,
+            None => true
+        };
+    }
+
+    impl BigNum {
+        pub fn checked_add(&self, other: &BigNum) -> Result<BigNum, BigNumError> {
+            check_operands(&[*self, *other])?;
+
+            // Infinity - Infinity
+            if self.is_infinite() && other.is_infinite() && self.is_negative != other.is_negative {
+                return Err(BigNumError::Indeterminate);
+            }
+
+            let result = *self + *other;
+
+            if result.is_infinite() && self.is_finite() && other.is_finite() {
+                return Err(BigNumError::Overflow);
+            }
+
+            // Zero from cancellation (like 5 - 5) is exact, so it isn't checked for underflow
+            return Ok(result);
+        }
+
+        pub fn checked_sub(&self, other: &BigNum) -> Result<BigNum, BigNumError> {
+            return self.checked_add(&-*other);
+        }
+
+        pub fn checked_mul(&self, other: &BigNum) -> Result<BigNum, BigNumError> {
+            check_operands(&[*self, *other])?;
+
+            // 0 * Infinity
+            if (self.is_zero() && other.is_infinite()) || (self.is_infinite() && other.is_zero()) {
+                return Err(BigNumError::Indeterminate);
+            }
+
+            return check_range(*self * *other, &[*self, *other]);
+        }
+
+        pub fn checked_div(&self, other: &BigNum) -> Result<BigNum, BigNumError> {
+            check_operands(&[*self, *other])?;
+
+            if other.is_zero() {
+                return Err(if self.is_zero() { BigNumError::Indeterminate } else { BigNumError::DivisionByZero });
+            }
+
+            if self.is_infinite() && other.is_infinite() {
+                return Err(BigNumError::Indeterminate);
+            }
+
+            return check_range(*self / *other, &[*self, *other]);
+        }
+
+        pub fn checked_recip(&self) -> Result<BigNum, BigNumError> {
+            return BigNum::ONE.checked_div(self);
+        }
+
+        pub fn checked_powf(&self, exp: f64) -> Result<BigNum, BigNumError> {
+            return self.checked_powb(&BigNum::from_f64(exp));
+        }
+
+        pub fn checked_powb(&self, exp: &BigNum) -> Result<BigNum, BigNumError> {
+            check_operands(&[*self, *exp])?;
+
+            if self.is_zero() && exp.is_negative && !exp.is_zero() {
+                return Err(BigNumError::DivisionByZero);
+            }
+
+            // Negative numbers only have real powers for integer exponents
+            if self.is_negative && !self.is_zero() && !is_integer(exp) {
+                return Err(BigNumError::NegativeDomain);
+            }
+
+            // Infinity^0 and 1^Infinity
+            if (self.is_infinite() && exp.is_zero()) || (*self == BigNum::ONE && exp.is_infinite()) {
+                return Err(BigNumError::Indeterminate);
+            }
+
+            return check_range(self.powb(*exp), &[*self, *exp]);
+        }
+
+        pub fn checked_sqrt(&self) -> Result<BigNum, BigNumError> {
+            check_operands(&[*self])?;
+
+            if self.is_negative && !self.is_zero() {
+                return Err(BigNumError::NegativeDomain);
+            }
+
+            return Ok(self.abs().sqrt());
+        }
+
+        /// Logarithm in base 10. Like IEEE 754, the logarithm of 0 counts as a division by zero.
+        pub fn checked_log10(&self) -> Result<BigNum, BigNumError> {
+            check_operands(&[*self])?;
+
+            if self.is_zero() {
+                return Err(BigNumError::DivisionByZero);
+            }
+
+            if self.is_negative {
+                return Err(BigNumError::NegativeDomain);
+            }
+
+            return Ok(self.log10());
+        }
+
+        /// Natural logarithm. Like IEEE 754, the logarithm of 0 counts as a division by zero.
+        pub fn checked_ln(&self) -> Result<BigNum, BigNumError> {
+            check_operands(&[*self])?;
+
+            if self.is_zero() {
+                return Err(BigNumError::DivisionByZero);
+            }
+
+            if self.is_negative {
+                return Err(BigNumError::NegativeDomain);
+            }
+
+            return Ok(self.ln());
+        }
+    }
+}
\ No newline at end of file
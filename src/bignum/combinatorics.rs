@@ -0,0 +1,221 @@
+mod combinations {
+    use std::f64::consts::LN_10;
+    use crate::bignum::{BigNum, BigNumError};
+    use crate::bignum::special::special_functions::{stirling_series, HALF_LN_TAU};
+
+    // Below this, C(n, k) is summed term by term instead of using Stirling's series
+    const SMALL_K: f64 = 15.0;
+
+    // Integers below this fit in a u64, so their results can be tried in exact arithmetic
+    const EXACT_LIMIT: f64 = 18446744073709551616.0;
+
+    // Checks that the argument is a non-negative integer, snapping away the rounding error of the exponent
+    fn integer_argument(value: BigNum) -> Result<BigNum, BigNumError> {
+        if value.is_nan() {
+            return Err(BigNumError::NanOperand);
+        }
+
+        if value.is_zero() {
+            return Ok(BigNum::ZERO);
+        }
+
+        if value.is_negative {
+            return Err(BigNumError::NegativeDomain);
+        }
+
+        if value.is_infinite() {
+            return Err(BigNumError::Overflow);
+        }
+
+        return match value.snapped_f64() {
+            Some(x) if x.fract() != 0.0 || x == 0.0 => Err(BigNumError::NonInteger),
+            Some(x) => Ok(BigNum::from_f64(x)),
+            // Every number this large is an integer
+            None => Ok(value)
+        };
+    }
+
+    fn to_exact(value: BigNum) -> Option<u128> {
+        return match value.snapped_f64() {
+            Some(x) if x < EXACT_LIMIT => Some(x as u128),
+            _ => None
+        };
+    }
+
+    // Adds two integers, exactly if they fit in an f64
+    fn add_integers(a: BigNum, b: BigNum) -> BigNum {
+        return match (a.snapped_f64(), b.snapped_f64()) {
+            (Some(a), Some(b)) => BigNum::from_f64(a + b),
+            _ => a + b
+        };
+    }
+
+    fn from_ln(ln: f64) -> Result<BigNum, BigNumError> {
+        let exponent = ln / LN_10;
+
+        if !exponent.is_finite() {
+            return Err(BigNumError::Overflow);
+        }
+
+        return Ok(BigNum::from_exponent(exponent, false));
+    }
+
+    // C(n, k), or None if it doesn't fit in a u128
+    fn exact_binomial(n: u128, k: u128) -> Option<u128> {
+        let k = k.min(n - k);
+        let mut result: u128 = 1;
+
+        // result is C(n - k + i, i) after each step, so the division is exact
+        for i in 1..=k {
+            result = result.checked_mul(n - k + i)? / i;
+        }
+
+        return Some(result);
+    }
+
+    // n! / (n - k)!, or None if it doesn't fit in a u128
+    fn exact_permutations(n: u128, k: u128) -> Option<u128> {
+        return ((n - k + 1)..=n).try_fold(1_u128, |product, factor| product.checked_mul(factor));
+    }
+
+    // ln(x!) - ((x + 1/2) ln(x) - x + ln(2π) / 2), the part of ln(x!) that Stirling's approximation leaves out
+    fn stirling_error(x: f64) -> f64 {
+        if x < SMALL_K {
+            return BigNum::ln_gamma_f64(x + 1.0).0 - ((x + 0.5) * x.ln() - x + HALF_LN_TAU);
+        }
+
+        return stirling_series(x);
+    }
+
+    // ln C(n, k) for 0 <= k <= n - k
+    fn ln_binomial_f64(n: f64, k: f64) -> f64 {
+        if k < SMALL_K {
+            return (1..=(k as u32)).map(|i| ((n - k + i as f64) / i as f64).ln()).sum();
+        }
+
+        // Subtracting the three ln(x!) would cancel out most of the digits for large n, so the
+        // n ln(n) - k ln(k) - m ln(m) part is written as a sum of positive terms instead
+        let m = n - k;
+
+        return k * (n / k).ln() - m * (-k / n).ln_1p()
+            + 0.5 * ((n / k).ln() - m.ln()) - HALF_LN_TAU
+            + stirling_error(n) - stirling_error(k) - stirling_error(m);
+    }
+
+    // ln C(n, k) for n too large for an f64 and 0 < k <= n - k
+    fn ln_binomial_huge(n: BigNum, k: f64) -> f64 {
+        // ln(n / k), and r = k / n, which may be too small for an f64
+        let ln_ratio = (n.exponent - k.log10()) * LN_10;
+        let r = (-ln_ratio).exp();
+
+        // m ln(n / m) = k (1 - r) (-ln(1 - r)) / r, which tends to k as r goes to 0
+        let spread = if r < 1e-8 { 1.0 - r / 2.0 } else { -(1.0 - r) * (-r).ln_1p() / r };
+
+        // The Stirling errors of n and m are below 1e-300
+        return k * (ln_ratio + spread) - 0.5 * ((-r).ln_1p() + k.ln()) - HALF_LN_TAU - stirling_error(k);
+    }
+
+    // ln C(n, k) for validated integers with k <= n
+    fn ln_binomial(n: BigNum, k: BigNum) -> Result<f64, BigNumError> {
+        if let (Some(n), Some(k)) = (n.snapped_f64(), k.snapped_f64()) {
+            return Ok(ln_binomial_f64(n, k.min(n - k)));
+        }
+
+        let m = n - k;
+        let smaller = if m < k { m } else { k };
+
+        return match smaller.snapped_f64() {
+            Some(0.0) => Ok(0.0),
+            Some(k) => Ok(ln_binomial_huge(n, k)),
+            // C(n, k) is at least 2^k
+            None => Err(BigNumError::Overflow)
+        };
+    }
+
+    impl BigNum {
+        /// Calculates the binomial coefficient C(n, k), the number of ways to choose k items out of n.
+        /// Results that fit in a u128 are exact before rounding to an f64, larger ones are accurate to about 1e-13.
+        pub fn binomial(n: BigNum, k: BigNum) -> Result<BigNum, BigNumError> {
+            let n = integer_argument(n)?;
+            let k = integer_argument(k)?;
+
+            if k > n {
+                return Ok(BigNum::ZERO);
+            }
+
+            if let (Some(n), Some(k)) = (to_exact(n), to_exact(k)) {
+                if let Some(result) = exact_binomial(n, k) {
+                    return Ok(BigNum::from_f64(result as f64));
+                }
+            }
+
+            return from_ln(ln_binomial(n, k)?);
+        }
+
+        /// Calculates n! / (n - k)!, the number of ordered ways to choose k items out of n.
+        pub fn permutations(n: BigNum, k: BigNum) -> Result<BigNum, BigNumError> {
+            let n = integer_argument(n)?;
+            let k = integer_argument(k)?;
+
+            if k > n {
+                return Ok(BigNum::ZERO);
+            }
+
+            if let (Some(n), Some(k)) = (to_exact(n), to_exact(k)) {
+                if let Some(result) = exact_permutations(n, k) {
+                    return Ok(BigNum::from_f64(result as f64));
+                }
+            }
+
+            // n! / (n - k)! = C(n, k) k!
+            let ln_factorial = match k.snapped_f64() {
+                Some(k) => BigNum::ln_gamma_f64(k + 1.0).0,
+                None => return Err(BigNumError::Overflow)
+            };
+
+            return from_ln(ln_binomial(n, k)? + ln_factorial);
+        }
+
+        /// Calculates (k1 + k2 + ...)! / (k1! k2! ...), the number of ways to split that many items into groups of those sizes.
+        pub fn multinomial(ks: &[BigNum]) -> Result<BigNum, BigNumError> {
+            let ks = ks.iter().map(|k| integer_argument(*k)).collect::<Result<Vec<BigNum>, BigNumError>>()?;
+
+            // The product of C(k1 + ... + ki, ki) over every i
+            let exact = ks.iter().try_fold((1_u128, 0_u128), |(product, total), k| {
+                let k = to_exact(*k)?;
+                let total = total.checked_add(k)?;
+
+                return Some((product.checked_mul(exact_binomial(total, k)?)?, total));
+            });
+
+            if let Some((result, _)) = exact {
+                return Ok(BigNum::from_f64(result as f64));
+            }
+
+            let mut total = BigNum::ZERO;
+            let mut ln = 0.0;
+
+            for k in ks {
+                total = add_integers(total, k);
+                ln += ln_binomial(total, k)?;
+            }
+
+            return from_ln(ln);
+        }
+
+        /// Calculates the nth Catalan number, C(2n, n) / (n + 1).
+        pub fn catalan(n: BigNum) -> Result<BigNum, BigNumError> {
+            let n = integer_argument(n)?;
+
+            if let Some(n) = to_exact(n) {
+                if let Some(result) = exact_binomial(2 * n, n) {
+                    return Ok(BigNum::from_f64((result / (n + 1)) as f64));
+                }
+            }
+
+            let ln_successor = add_integers(n, BigNum::ONE).exponent * LN_10;
+
+            return from_ln(ln_binomial(add_integers(n, n), n)? - ln_successor);
+        }
+    }
+}
\ No newline at end of file
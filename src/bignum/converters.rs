@@ -1,6 +1,13 @@
 mod converters {
-    use std::num::ParseFloatError;
-    use crate::bignum::BigNum;
+    use std::str::FromStr;
+    use crate::bignum::{BigNum, ParseBigNumError};
+
+    // From quecto (10^-30) to quetta (10^30), in steps of 1000
+    const SI_PREFIXES: [&str; 21] = [
+        "q", "r", "y", "z", "a", "f", "p", "n", "µ", "m",
+        "",
+        "k", "M", "G", "T", "P", "E", "Z", "Y", "R", "Q"
+    ];
 
     impl BigNum {
         pub fn from_exponent(exponent: f64, is_negative: bool) -> BigNum {
@@ -21,99 +28,75 @@ mod converters {
             return Self::from_f64(value as f64);
         }
 
-        pub fn from_string(value: &str) -> Result<BigNum, String> {
+        pub fn from_string(value: &str) -> Result<BigNum, ParseBigNumError> {
             if value.is_empty() {
-                return Err("Value is empty".into());
+                return Err(ParseBigNumError::Empty);
             }
 
             match value.to_lowercase().as_str() {
                 "inf"       => return Ok(BigNum::INFINITY),
+                "+inf"      => return Ok(BigNum::INFINITY),
                 "infinity"  => return Ok(BigNum::INFINITY),
+                "+infinity" => return Ok(BigNum::INFINITY),
                 "-inf"      => return Ok(BigNum::NEG_INFINITY),
                 "-infinity" => return Ok(BigNum::NEG_INFINITY),
                 "nan"       => return Ok(BigNum::NAN),
                 _ => {}
             }
 
+            let bytes = value.as_bytes();
 
-            let first_char: char = value.as_bytes()[0].into();
-
-            let is_negative = match first_char {
-                '-' => true,
-                '+' => false,
-                char => {
-                    if char.is_numeric() || char.to_ascii_lowercase() == 'e' {
-                        false
-                    } else {
-                        return Err("Invalid first character".into());
-                    }
-                }
+            let (is_negative, start) = match bytes[0] {
+                b'-' => (true, 1),
+                b'+' => (false, 1),
+                _ => (false, 0)
             };
 
-            let has_sign_char = match first_char {
-                '-' => true,
-                '+' => true,
-                _ => false
-            };
-
-            let is_char_e: fn(char) -> bool = |c| c.to_ascii_lowercase() == 'e';
-            
-            let exponent = {
-                // check if string has an 'e' or an 'E', if it does, get the position
-                if let Some(e_position) = value.find(is_char_e) {
-
-                    // lhs: coefficient, before 'e', like the '1.23' in '1.23e456'
-                    // rhs: exponent, after 'e', like the '456' in '1.23e456'
-                    let start = if has_sign_char { 1 } else { 0 };
-                    let lhs = &value[start..e_position];
-                    let rhs = &value[(e_position + 1)..];
-
-                    if lhs.is_empty() {
-                        // logarithm notation (like 'e1234', '-e11', 'e-11')
-                        let parse_result: Result<f64, ParseFloatError> = rhs.parse();
-
-                        if let Err(e) = parse_result {
-                            return Err(e.to_string());
-                        }
-
-                        parse_result.unwrap()
-                    } else {
-                        // scientific notation (like '1.23e456', '-1e12', '1e-12')
-                        let coefficient: Result<f64, ParseFloatError> = lhs.parse();
-
-                        if let Err(e) = coefficient {
-                            return Err(e.to_string());
-                        }
-
-                        let coefficient = coefficient.unwrap();
-
-
-                        let exponent: Result<f64, ParseFloatError> = rhs.parse();
+            if matches!(bytes.get(start), Some(b'-') | Some(b'+')) {
+                return Err(ParseBigNumError::InvalidSign { position: start });
+            }
 
-                        if let Err(e) = exponent {
-                            return Err(e.to_string());
-                        }
+            // coefficient: before 'e', like the '1.23' in '1.23e456'
+            // It can be left out in logarithm notation (like 'e1234', '-e11', 'e-11')
+            let coefficient_end = scan_decimal(bytes, start);
+            let has_exponent = matches!(bytes.get(coefficient_end), Some(b'e') | Some(b'E'));
 
-                        let exponent = exponent.unwrap();
+            let coefficient = if coefficient_end == start && has_exponent {
+                0.0 // log10(1)
+            } else {
+                match parse_decimal_log10(&value[start..coefficient_end]) {
+                    Some(coefficient) => coefficient,
+                    None => return Err(ParseBigNumError::InvalidMantissa { position: start })
+                }
+            };
 
-                        // log_10(coefficient * 10^exponent) simplifies to this
-                        coefficient.log10() + exponent
-                    }
-                } else {
-                    // Regular number (like '123.456', '-123.456')
-                    let parse_result: Result<f64, ParseFloatError> = value.parse();
+            if !has_exponent {
+                // Regular number (like '123.456', '-123.456')
+                if coefficient_end != value.len() {
+                    return Err(ParseBigNumError::TrailingCharacters { position: coefficient_end });
+                }
 
-                    if let Err(e) = parse_result {
-                        return Err(e.to_string());
-                    }
+                return Ok(BigNum {
+                    is_negative,
+                    exponent: coefficient
+                });
+            }
 
-                    parse_result.unwrap().abs().log10()
-                }
-            };
+            // exponent: after 'e', like the '456' in '1.23e456' or the '1.23e45' in '3e1.23e45'
+            let exponent = parse_exponent_tower(value, coefficient_end + 1)?;
 
             return Ok(BigNum {
                 is_negative,
-                exponent
+                exponent: if coefficient == f64::NEG_INFINITY {
+                    // 0 * 10^x = 0, even if x overflowed
+                    f64::NEG_INFINITY
+                } else if coefficient == 0.0 {
+                    // Adding 0 would turn an exponent of -0 (like in 'e-0') into +0
+                    exponent
+                } else {
+                    // log_10(coefficient * 10^exponent) simplifies to this
+                    coefficient + exponent
+                }
             });
         }
 
@@ -153,7 +136,7 @@ mod converters {
                 return self.to_string_num(precision).unwrap();
             }
 
-            let coefficient = 10_f64.powf(self.exponent.fract());
+            let coefficient = 10_f64.powf(self.exponent - self.exponent.floor());
             let exponent = self.exponent.floor();
 
             if self.exponent.abs() < 1e9 {
@@ -181,6 +164,89 @@ mod converters {
             }
         }
 
+        /// Formats the number in logarithm notation with the shortest exponent that
+        /// `from_string` parses back to exactly the same BigNum, e.g. 'e123.456' or '-e1.5e300'.
+        pub fn to_string_exact(&self) -> String {
+            if self.is_nan() {
+                return "NaN".into();
+            }
+
+            let sign = if self.is_negative { "-" } else { "" };
+
+            if self.is_zero() {
+                return format!("{}0", sign);
+            } else if self.is_infinite() {
+                return format!("{}inf", sign);
+            }
+
+            // Both forms print the shortest digits that round-trip, but in a different layout
+            let plain = format!("{}", self.exponent);
+            let scientific = format!("{:e}", self.exponent);
+
+            return format!(
+                "{}e{}",
+                sign,
+                if scientific.len() < plain.len() { scientific } else { plain }
+            );
+        }
+
+        /// Formats the number with an exponent that is a multiple of 3, e.g. '123.40e6'.
+        /// Exponents of a billion or more use `to_string_sci` instead.
+        pub fn to_string_eng(&self, precision: usize) -> String {
+            if !self.is_finite() || self.is_zero() || self.exponent.abs() >= 1e9 {
+                return self.to_string_sci(precision);
+            }
+
+            let (mantissa, group) = self.split_groups(3.0, precision);
+
+            return format!(
+                "{}{:.*}{}",
+                if self.is_negative { "-" } else { "" },
+                precision,
+                mantissa,
+                if group == 0.0 { String::new() } else { format!("e{:.0}", group * 3.0) }
+            );
+        }
+
+        /// Formats the number with an SI prefix in front of `unit`, e.g. '12.30 Mg' for 1.23e7 grams.
+        /// Numbers past quetta (10^30) or below quecto (10^-30) use `to_string_sci` instead.
+        pub fn to_string_si(&self, precision: usize, unit: &str) -> String {
+            if !self.is_finite() || self.is_zero() {
+                return format!("{} {}", self.to_string_sci(precision), unit).trim_end().into();
+            }
+
+            let (mantissa, group) = self.split_groups(3.0, precision);
+
+            let prefix = match SI_PREFIXES.get((group + 10.0) as usize) {
+                Some(prefix) if group.abs() <= 10.0 => prefix,
+                _ => return format!("{} {}", self.to_string_sci(precision), unit).trim_end().into()
+            };
+
+            return format!(
+                "{}{:.*} {}{}",
+                if self.is_negative { "-" } else { "" },
+                precision,
+                mantissa,
+                prefix,
+                unit
+            ).trim_end().into();
+        }
+
+        // Splits the absolute value into a mantissa and a number of groups of `group_size` digits,
+        // with the mantissa already rounded to `precision` decimal places so it stays below 10^group_size
+        pub(crate) fn split_groups(&self, group_size: f64, precision: usize) -> (f64, f64) {
+            let mut group = (self.exponent / group_size).floor();
+            let mut mantissa = 10_f64.powf(self.exponent - group * group_size);
+
+            let scale = 10_f64.powi(precision.min(17) as i32);
+            if (mantissa * scale).round() / scale >= 10_f64.powf(group_size) {
+                mantissa /= 10_f64.powf(group_size);
+                group += 1.0;
+            }
+
+            return (mantissa, group);
+        }
+
         pub fn to_string_log(&self, precision: usize) -> String {
             if self.exponent.is_nan() {
                 return "NaN".into();
@@ -214,6 +280,114 @@ mod converters {
         }
     }
 
+    // Finds where a decimal (like '123.456') that begins at `start` ends
+    fn scan_decimal(bytes: &[u8], start: usize) -> usize {
+        let mut end = start;
+        let mut has_dot = false;
+
+        while let Some(&byte) = bytes.get(end) {
+            if byte == b'.' && !has_dot {
+                has_dot = true;
+            } else if !byte.is_ascii_digit() {
+                break;
+            }
+
+            end += 1;
+        }
+
+        return end;
+    }
+
+    // Calculates log10 of an unsigned decimal (like '123.456') straight from its digits,
+    // so numbers too long for an f64 don't overflow to infinity or underflow to zero
+    fn parse_decimal_log10(value: &str) -> Option<f64> {
+        let (integer, fraction) = match value.find('.') {
+            Some(dot_position) => (&value[..dot_position], &value[(dot_position + 1)..]),
+            None => (value, "")
+        };
+
+        let is_digits: fn(&str) -> bool = |s| s.bytes().all(|b| b.is_ascii_digit());
+
+        if (integer.is_empty() && fraction.is_empty()) || !is_digits(integer) || !is_digits(fraction) {
+            return None;
+        }
+
+        // Numbers that fit in an f64 are parsed as usual, to keep the standard rounding
+        let float: f64 = value.parse().ok()?;
+
+        if float.is_normal() {
+            return Some(float.log10());
+        }
+
+        let digits: Vec<u8> = integer.bytes().chain(fraction.bytes()).collect();
+
+        let first_significant = match digits.iter().position(|&digit| digit != b'0') {
+            Some(position) => position,
+            None => return Some(f64::NEG_INFINITY) // log10(0) = -Infinity
+        };
+
+        // 17 significant digits is all an f64 can hold
+        let significant = &digits[first_significant..(first_significant + 17).min(digits.len())];
+        let mantissa: f64 = std::str::from_utf8(significant).ok()?.parse().ok()?;
+
+        // 123.456 = 123456 * 10^(3 - 6), where 3 is the number of integer digits and 6 the number of digits read
+        let exponent = integer.len() as f64 - (first_significant + significant.len()) as f64;
+
+        return Some(mantissa.log10() + exponent);
+    }
+
+    // Parses the exponent that begins at `start`, right after an 'e'.
+    // It may be a tower of exponents itself, like the '1e1e5' in '1e1e1e5'.
+    fn parse_exponent_tower(value: &str, start: usize) -> Result<f64, ParseBigNumError> {
+        let bytes = value.as_bytes();
+
+        let (sign, coefficient_start) = match bytes.get(start) {
+            Some(b'-') => (-1.0, start + 1),
+            Some(b'+') => (1.0, start + 1),
+            _ => (1.0, start)
+        };
+
+        if matches!(bytes.get(coefficient_start), Some(b'-') | Some(b'+')) {
+            return Err(ParseBigNumError::InvalidSign { position: coefficient_start });
+        }
+
+        let coefficient_end = scan_decimal(bytes, coefficient_start);
+        let coefficient = &value[coefficient_start..coefficient_end];
+        let has_exponent = matches!(bytes.get(coefficient_end), Some(b'e') | Some(b'E'));
+
+        let has_digits = coefficient.bytes().any(|b| b.is_ascii_digit());
+
+        // The coefficient is optional, like in logarithm notation ('1ee5' is 1e100000)
+        if !has_digits && (!coefficient.is_empty() || !has_exponent) {
+            return Err(ParseBigNumError::InvalidExponent { position: coefficient_start });
+        }
+
+        let invalid_exponent = ParseBigNumError::InvalidExponent { position: start };
+
+        if !has_exponent {
+            if coefficient_end != value.len() {
+                return Err(ParseBigNumError::TrailingCharacters { position: coefficient_end });
+            }
+
+            return value[start..].parse().map_err(|_| invalid_exponent);
+        }
+
+        let exponent = parse_exponent_tower(value, coefficient_end + 1)?;
+
+        // Plain exponents (like '456', '-1.23e45') are parsed as usual, to keep the standard rounding
+        if has_digits && !value[(coefficient_end + 1)..].contains(['e', 'E']) {
+            return value[start..].parse().map_err(|_| invalid_exponent);
+        }
+
+        let coefficient: f64 = if has_digits {
+            coefficient.parse().map_err(|_| invalid_exponent)?
+        } else {
+            1.0
+        };
+
+        return Ok(sign * coefficient * 10_f64.powf(exponent));
+    }
+
     impl From<f64> for BigNum {
         fn from(value: f64) -> Self {
             return BigNum::from_f64(value);
@@ -226,9 +400,19 @@ mod converters {
         }
     }
 
-    impl From<&str> for BigNum {
-        fn from(value: &str) -> Self {
-            return BigNum::from_string(value).unwrap();
+    impl FromStr for BigNum {
+        type Err = ParseBigNumError;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            return BigNum::from_string(value);
+        }
+    }
+
+    impl TryFrom<&str> for BigNum {
+        type Error = ParseBigNumError;
+
+        fn try_from(value: &str) -> Result<Self, Self::Error> {
+            return BigNum::from_string(value);
         }
     }
 }
\ No newline at end of file
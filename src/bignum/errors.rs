@@ -0,0 +1,86 @@
+mod err {
+    use std::{error::Error, fmt};
+    use crate::bignum::{BigNumError, BinaryDecodeError, JsonError, ParseBigNumError, SaveDecodeError};
+
+    impl fmt::Display for ParseBigNumError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            return match self {
+                ParseBigNumError::Empty => write!(f, "cannot parse BigNum from empty string"),
+                ParseBigNumError::InvalidSign { position } => write!(f, "invalid sign at byte {}", position),
+                ParseBigNumError::InvalidMantissa { position } => write!(f, "invalid mantissa at byte {}", position),
+                ParseBigNumError::InvalidExponent { position } => write!(f, "invalid exponent at byte {}", position),
+                ParseBigNumError::TrailingCharacters { position } => write!(f, "unexpected characters at byte {}", position),
+                ParseBigNumError::InvalidSuffix { position } => write!(f, "unknown suffix at byte {}", position)
+            };
+        }
+    }
+
+    impl Error for ParseBigNumError {}
+
+    impl fmt::Display for BinaryDecodeError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            return match self {
+                BinaryDecodeError::InvalidMagic => write!(f, "missing BigNum header"),
+                BinaryDecodeError::UnsupportedVersion { version } => write!(f, "unsupported format version {}", version),
+                BinaryDecodeError::UnexpectedEnd { position } => write!(f, "unexpected end of data at byte {}", position),
+                BinaryDecodeError::InvalidSign { position, value } => write!(f, "invalid sign byte {:#04x} at byte {}", value, position),
+                BinaryDecodeError::TrailingBytes { position } => write!(f, "unexpected bytes after the data at byte {}", position)
+            };
+        }
+    }
+
+    impl Error for BinaryDecodeError {}
+
+    impl fmt::Display for JsonError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            return match self {
+                JsonError::UnexpectedEnd => write!(f, "unexpected end of JSON"),
+                JsonError::UnexpectedCharacter { position } => write!(f, "unexpected character at byte {}", position),
+                JsonError::InvalidEscape { position } => write!(f, "invalid escape sequence at byte {}", position),
+                JsonError::InvalidNumber { position, error } => write!(f, "invalid number at byte {}: {}", position, error),
+                JsonError::TrailingCharacters { position } => write!(f, "unexpected characters after the JSON at byte {}", position)
+            };
+        }
+    }
+
+    impl Error for JsonError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            return match self {
+                JsonError::InvalidNumber { error, .. } => Some(error),
+                _ => None
+            };
+        }
+    }
+
+    impl fmt::Display for BigNumError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            return match self {
+                BigNumError::Overflow => write!(f, "result is too large for BigNum"),
+                BigNumError::Underflow => write!(f, "result is too small for BigNum"),
+                BigNumError::NegativeDomain => write!(f, "operation is undefined for negative numbers"),
+                BigNumError::NanOperand => write!(f, "operand is NaN"),
+                BigNumError::Indeterminate => write!(f, "result is indeterminate"),
+                BigNumError::DivisionByZero => write!(f, "division by zero"),
+                BigNumError::NonInteger => write!(f, "operation is only defined for integers")
+            };
+        }
+    }
+
+    impl Error for BigNumError {}
+
+    impl fmt::Display for SaveDecodeError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            return match self {
+                SaveDecodeError::InvalidCharacter { position } => write!(f, "invalid character at position {}", position),
+                SaveDecodeError::InvalidLength => write!(f, "save string has an invalid length, it may be cut off"),
+                SaveDecodeError::ChecksumMismatch { expected, found } => write!(f, "checksum mismatch (expected {:08x}, found {:08x}), the save string is corrupted", expected, found),
+                SaveDecodeError::UnsupportedVersion { version } => write!(f, "unsupported save version {}", version),
+                SaveDecodeError::UnknownTag { position, tag } => write!(f, "unknown value type {} at byte {}", tag, position),
+                SaveDecodeError::UnexpectedEnd { position } => write!(f, "unexpected end of save data at byte {}", position),
+                SaveDecodeError::IntegerOverflow { position } => write!(f, "integer too large at byte {}", position)
+            };
+        }
+    }
+
+    impl Error for SaveDecodeError {}
+}
\ No newline at end of file
@@ -0,0 +1,496 @@
+mod hybrid_num {
+    use std::{cmp::Ordering, fmt, ops};
+    use crate::bignum::{BigNum, HybridNum};
+
+    // 2^53, the first integer where f64 starts skipping integers
+    const EXACT_LIMIT: i64 = 1 << 53;
+
+    // log10(2^53), anything above this can't be exact
+    const EXACT_LIMIT_LOG10: f64 = 15.954589770191003;
+
+    // Conversions
+    impl HybridNum {
+        pub fn from_i64(value: i64) -> HybridNum {
+            if value.unsigned_abs() < EXACT_LIMIT as u64 {
+                return HybridNum::Exact(value);
+            }
+
+            return HybridNum::Approximate(BigNum::from_f64(value as f64));
+        }
+
+        pub fn from_f64(value: f64) -> HybridNum {
+            if value.fract() == 0.0 && value.abs() < EXACT_LIMIT as f64 {
+                return HybridNum::Exact(value as i64);
+            }
+
+            return HybridNum::Approximate(BigNum::from_f64(value));
+        }
+
+        /// Always approximate, since the BigNum may carry rounding errors. Use `round_to_exact` to snap it to an integer.
+        pub fn from_bignum(value: BigNum) -> HybridNum {
+            return HybridNum::Approximate(value);
+        }
+
+        /// Becomes exact if the value is within rounding error of an integer below 2^53, for results known to be integers.
+        pub fn round_to_exact(&self) -> Self {
+            if let HybridNum::Approximate(value) = self {
+                if value.exponent < EXACT_LIMIT_LOG10 {
+                    if let Some(float) = value.snapped_f64().filter(|float| float.fract() == 0.0) {
+                        return HybridNum::from_f64(float);
+                    }
+                }
+            }
+
+            return *self;
+        }
+
+        pub fn to_bignum(&self) -> BigNum {
+            return match self {
+                HybridNum::Exact(value) => BigNum::from_f64(*value as f64),
+                HybridNum::Approximate(value) => *value
+            };
+        }
+
+        pub fn to_f64(&self) -> Option<f64> {
+            return match self {
+                HybridNum::Exact(value) => Some(*value as f64),
+                HybridNum::Approximate(value) => value.to_f64()
+            };
+        }
+
+        pub fn to_i64(&self) -> Option<i64> {
+            return match self {
+                HybridNum::Exact(value) => Some(*value),
+                HybridNum::Approximate(_) => None
+            };
+        }
+
+        pub fn is_exact(&self) -> bool {
+            return matches!(self, HybridNum::Exact(_));
+        }
+    }
+
+    impl From<i64> for HybridNum {
+        fn from(value: i64) -> Self {
+            return HybridNum::from_i64(value);
+        }
+    }
+
+    impl From<f64> for HybridNum {
+        fn from(value: f64) -> Self {
+            return HybridNum::from_f64(value);
+        }
+    }
+
+    impl From<BigNum> for HybridNum {
+        fn from(value: BigNum) -> Self {
+            return HybridNum::from_bignum(value);
+        }
+    }
+
+    impl From<HybridNum> for BigNum {
+        fn from(value: HybridNum) -> Self {
+            return value.to_bignum();
+        }
+    }
+
+    // Classification
+    impl HybridNum {
+        pub fn is_nan(&self) -> bool {
+            return match self {
+                HybridNum::Exact(_) => false,
+                HybridNum::Approximate(value) => value.is_nan()
+            };
+        }
+
+        pub fn is_infinite(&self) -> bool {
+            return match self {
+                HybridNum::Exact(_) => false,
+                HybridNum::Approximate(value) => value.is_infinite()
+            };
+        }
+
+        pub fn is_zero(&self) -> bool {
+            return match self {
+                HybridNum::Exact(value) => *value == 0,
+                HybridNum::Approximate(value) => value.is_zero()
+            };
+        }
+
+        pub fn is_sign_negative(&self) -> bool {
+            return match self {
+                HybridNum::Exact(value) => *value < 0,
+                HybridNum::Approximate(value) => value.is_sign_negative()
+            };
+        }
+
+        pub fn is_sign_positive(&self) -> bool {
+            return !self.is_sign_negative();
+        }
+    }
+
+    impl ops::Add for HybridNum {
+        type Output = HybridNum;
+
+        fn add(self, other: Self) -> Self::Output {
+            if let (HybridNum::Exact(a), HybridNum::Exact(b)) = (self, other) {
+                if let Some(result) = a.checked_add(b) {
+                    return HybridNum::from_i64(result);
+                }
+            }
+
+            return HybridNum::from_bignum(self.to_bignum() + other.to_bignum());
+        }
+    }
+    impl ops::Add<f64> for HybridNum {
+        type Output = HybridNum;
+
+        fn add(self, other: f64) -> Self::Output {
+            return self + HybridNum::from_f64(other);
+        }
+    }
+    impl ops::Add<HybridNum> for f64 {
+        type Output = HybridNum;
+
+        fn add(self, other: HybridNum) -> Self::Output {
+            return HybridNum::from_f64(self) + other;
+        }
+    }
+
+    impl ops::AddAssign for HybridNum {
+        fn add_assign(&mut self, other: Self) {
+            *self = *self + other;
+        }
+    }
+    impl ops::AddAssign<f64> for HybridNum {
+        fn add_assign(&mut self, other: f64) {
+            *self = *self + HybridNum::from_f64(other);
+        }
+    }
+    impl ops::AddAssign<HybridNum> for f64 {
+        fn add_assign(&mut self, other: HybridNum) {
+            *self = *self + other.to_f64().unwrap_or(f64::MAX);
+        }
+    }
+
+    impl ops::Neg for HybridNum {
+        type Output = HybridNum;
+
+        fn neg(self) -> Self::Output {
+            return match self {
+                HybridNum::Exact(value) => HybridNum::Exact(-value),
+                HybridNum::Approximate(value) => HybridNum::Approximate(-value)
+            };
+        }
+    }
+
+    impl ops::Sub for HybridNum {
+        type Output = HybridNum;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            if let (HybridNum::Exact(a), HybridNum::Exact(b)) = (self, rhs) {
+                if let Some(result) = a.checked_sub(b) {
+                    return HybridNum::from_i64(result);
+                }
+            }
+
+            return HybridNum::from_bignum(self.to_bignum() - rhs.to_bignum());
+        }
+    }
+    impl ops::Sub<f64> for HybridNum {
+        type Output = HybridNum;
+
+        fn sub(self, other: f64) -> Self::Output {
+            return self - HybridNum::from_f64(other);
+        }
+    }
+    impl ops::Sub<HybridNum> for f64 {
+        type Output = HybridNum;
+
+        fn sub(self, other: HybridNum) -> Self::Output {
+            return HybridNum::from_f64(self) - other;
+        }
+    }
+
+    impl ops::SubAssign for HybridNum {
+        fn sub_assign(&mut self, other: Self) {
+            *self = *self - other;
+        }
+    }
+    impl ops::SubAssign<f64> for HybridNum {
+        fn sub_assign(&mut self, other: f64) {
+            *self = *self - HybridNum::from_f64(other);
+        }
+    }
+    impl ops::SubAssign<HybridNum> for f64 {
+        fn sub_assign(&mut self, other: HybridNum) {
+            *self = *self - other.to_f64().unwrap_or(f64::MAX);
+        }
+    }
+
+    impl ops::Mul for HybridNum {
+        type Output = HybridNum;
+
+        fn mul(self, rhs: Self) -> Self::Output {
+            if let (HybridNum::Exact(a), HybridNum::Exact(b)) = (self, rhs) {
+                if let Some(result) = a.checked_mul(b) {
+                    return HybridNum::from_i64(result);
+                }
+            }
+
+            return HybridNum::from_bignum(self.to_bignum() * rhs.to_bignum());
+        }
+    }
+    impl ops::Mul<f64> for HybridNum {
+        type Output = HybridNum;
+
+        fn mul(self, other: f64) -> Self::Output {
+            return self * HybridNum::from_f64(other);
+        }
+    }
+    impl ops::Mul<HybridNum> for f64 {
+        type Output = HybridNum;
+
+        fn mul(self, other: HybridNum) -> Self::Output {
+            return HybridNum::from_f64(self) * other;
+        }
+    }
+
+    impl ops::MulAssign for HybridNum {
+        fn mul_assign(&mut self, other: Self) {
+            *self = *self * other;
+        }
+    }
+    impl ops::MulAssign<f64> for HybridNum {
+        fn mul_assign(&mut self, other: f64) {
+            *self = *self * HybridNum::from_f64(other);
+        }
+    }
+    impl ops::MulAssign<HybridNum> for f64 {
+        fn mul_assign(&mut self, other: HybridNum) {
+            *self = *self * other.to_f64().unwrap_or(f64::MAX);
+        }
+    }
+
+    impl ops::Div for HybridNum {
+        type Output = HybridNum;
+
+        fn div(self, rhs: Self) -> Self::Output {
+            if let (HybridNum::Exact(a), HybridNum::Exact(b)) = (self, rhs) {
+                // Only exact if the division has no remainder
+                if b != 0 && a % b == 0 {
+                    return HybridNum::from_i64(a / b);
+                }
+            }
+
+            return HybridNum::from_bignum(self.to_bignum() / rhs.to_bignum());
+        }
+    }
+    impl ops::Div<f64> for HybridNum {
+        type Output = HybridNum;
+
+        fn div(self, other: f64) -> Self::Output {
+            return self / HybridNum::from_f64(other);
+        }
+    }
+    impl ops::Div<HybridNum> for f64 {
+        type Output = HybridNum;
+
+        fn div(self, other: HybridNum) -> Self::Output {
+            return HybridNum::from_f64(self) / other;
+        }
+    }
+
+    impl ops::DivAssign for HybridNum {
+        fn div_assign(&mut self, other: Self) {
+            *self = *self / other;
+        }
+    }
+    impl ops::DivAssign<f64> for HybridNum {
+        fn div_assign(&mut self, other: f64) {
+            *self = *self / HybridNum::from_f64(other);
+        }
+    }
+    impl ops::DivAssign<HybridNum> for f64 {
+        fn div_assign(&mut self, other: HybridNum) {
+            *self = *self / other.to_f64().unwrap_or(f64::MAX);
+        }
+    }
+
+    impl ops::Rem for HybridNum {
+        type Output = HybridNum;
+
+        // Exact remainders take the sign of the dividend, like the integer and f64 remainders
+        fn rem(self, rhs: Self) -> Self::Output {
+            if let (HybridNum::Exact(a), HybridNum::Exact(b)) = (self, rhs) {
+                if b != 0 {
+                    return HybridNum::Exact(a % b);
+                }
+            }
+
+            return HybridNum::from_bignum(self.to_bignum() % rhs.to_bignum());
+        }
+    }
+    impl ops::Rem<f64> for HybridNum {
+        type Output = HybridNum;
+
+        fn rem(self, other: f64) -> Self::Output {
+            return self % HybridNum::from_f64(other);
+        }
+    }
+    impl ops::Rem<HybridNum> for f64 {
+        type Output = HybridNum;
+
+        fn rem(self, other: HybridNum) -> Self::Output {
+            return HybridNum::from_f64(self) % other;
+        }
+    }
+
+    impl ops::RemAssign for HybridNum {
+        fn rem_assign(&mut self, other: Self) {
+            *self = *self % other;
+        }
+    }
+    impl ops::RemAssign<f64> for HybridNum {
+        fn rem_assign(&mut self, other: f64) {
+            *self = *self % HybridNum::from_f64(other);
+        }
+    }
+    impl ops::RemAssign<HybridNum> for f64 {
+        fn rem_assign(&mut self, other: HybridNum) {
+            *self = *self % other.to_f64().unwrap_or(f64::MAX);
+        }
+    }
+
+    // Exponentiation
+    impl HybridNum {
+        pub fn powi(&self, exp: i32) -> Self {
+            if let HybridNum::Exact(value) = self {
+                if exp >= 0 {
+                    if let Some(result) = value.checked_pow(exp as u32) {
+                        return HybridNum::from_i64(result);
+                    }
+                }
+            }
+
+            return HybridNum::from_bignum(self.to_bignum().powi(exp));
+        }
+
+        pub fn powf(&self, exp: f64) -> Self {
+            if exp.fract() == 0.0 && exp.abs() <= i32::MAX as f64 {
+                return self.powi(exp as i32);
+            }
+
+            return HybridNum::from_bignum(self.to_bignum().powf(exp));
+        }
+
+        pub fn powb(&self, exp: HybridNum) -> Self {
+            if let HybridNum::Exact(exp) = exp {
+                if exp.unsigned_abs() <= i32::MAX as u64 {
+                    return self.powi(exp as i32);
+                }
+            }
+
+            return HybridNum::from_bignum(self.to_bignum().powb(exp.to_bignum()));
+        }
+
+        /// Exact for perfect squares.
+        pub fn sqrt(&self) -> Self {
+            if let HybridNum::Exact(value) = self {
+                if *value >= 0 && value.isqrt() * value.isqrt() == *value {
+                    return HybridNum::Exact(value.isqrt());
+                }
+            }
+
+            return HybridNum::from_bignum(self.to_bignum().sqrt());
+        }
+
+        /// Exact for perfect cubes.
+        pub fn cbrt(&self) -> Self {
+            if let HybridNum::Exact(value) = self {
+                // The rounded f64 cube root is the integer root if there is one
+                let root = (*value as f64).cbrt().round() as i64;
+
+                if root.checked_pow(3) == Some(*value) {
+                    return HybridNum::Exact(root);
+                }
+            }
+
+            return HybridNum::from_bignum(self.to_bignum().cbrt());
+        }
+    }
+
+    // Integer operations
+    impl HybridNum {
+        // The result of rounding is an integer, so it's exact below 2^53
+        fn round_with(&self, rounding: fn(f64) -> f64) -> Self {
+            return match self {
+                HybridNum::Exact(_) => *self,
+                HybridNum::Approximate(value) => match value.to_f64() {
+                    Some(float) => HybridNum::from_f64(rounding(float)),
+                    // Too large for an f64, so it's an integer already
+                    None => *self
+                }
+            };
+        }
+
+        pub fn floor(&self) -> Self {
+            return self.round_with(f64::floor);
+        }
+
+        pub fn ceil(&self) -> Self {
+            return self.round_with(f64::ceil);
+        }
+
+        pub fn round(&self) -> Self {
+            return self.round_with(f64::round);
+        }
+
+        pub fn trunc(&self) -> Self {
+            return self.round_with(f64::trunc);
+        }
+
+        pub fn fract(&self) -> Self {
+            return match self {
+                HybridNum::Exact(_) => HybridNum::Exact(0),
+                HybridNum::Approximate(value) => HybridNum::from_bignum(value.fract())
+            };
+        }
+    }
+
+    // Sign operations
+    impl HybridNum {
+        pub fn abs(&self) -> Self {
+            return match self {
+                HybridNum::Exact(value) => HybridNum::Exact(value.abs()),
+                HybridNum::Approximate(value) => HybridNum::Approximate(value.abs())
+            };
+        }
+
+        pub fn signum(&self) -> Self {
+            return match self {
+                HybridNum::Exact(value) => HybridNum::Exact(value.signum()),
+                HybridNum::Approximate(value) => HybridNum::from_bignum(value.signum())
+            };
+        }
+    }
+
+    impl PartialOrd for HybridNum {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            if let (HybridNum::Exact(a), HybridNum::Exact(b)) = (self, other) {
+                return Some(a.cmp(b));
+            }
+
+            return self.to_bignum().partial_cmp(&other.to_bignum());
+        }
+    }
+
+    impl fmt::Display for HybridNum {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            return match self {
+                HybridNum::Exact(value) => write!(f, "{}", value),
+                HybridNum::Approximate(value) => write!(f, "{}", value)
+            };
+        }
+    }
+}
\ No newline at end of file
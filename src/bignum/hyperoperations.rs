@@ -0,0 +1,193 @@
+mod hyper {
+    use std::f64::consts::{E, LN_10};
+    use crate::bignum::BigNum;
+
+    // Towers that haven't converged by then are returned as they are
+    const MAX_ITERATIONS: u32 = 10_000;
+
+    // e^(1/e), the largest base whose infinite tower converges
+    const CONVERGENCE_LIMIT: f64 = 1.444667861009766;
+
+    // Towers of bases just above e^(1/e) crawl past e while they are closer to it than this
+    const PLATEAU_WIDTH: f64 = 1e-3;
+
+    // What 1/e and ln(10) lose to rounding, so that ln(base) - 1/e keeps its digits for bases just above e^(1/e)
+    const INVERSE_E_LOW: f64 = -1.2428753672788363e-17;
+    const LN_10_LOW: f64 = -2.1707562233822494e-16;
+
+    // Levels of a tower this close together are taken to be the same number
+    const FIXED_POINT_TOLERANCE: f64 = 4.0 * f64::EPSILON;
+
+    // e^(-1/e), the smallest value of x^x
+    const MIN_SELF_POWER: f64 = 0.6922006275553464;
+
+    // Tetration
+    impl BigNum {
+        /// Calculates self↑↑height, a power tower of `height` copies of self.
+        /// Fractional heights use the linear approximation, where self↑↑x = x + 1 for -1 < x <= 0.
+        pub fn tetrate(&self, height: f64) -> Self {
+            if self.is_nan() || height.is_nan() || height < -1.0 {
+                return BigNum::NAN;
+            }
+
+            if self.is_negative && !self.is_zero() {
+                return BigNum::NAN;
+            }
+
+            // x↑↑h = x^(x↑↑(h - 1)), so we start from the fractional part and go up one level at a time
+            let levels = height.ceil();
+            let mut result = if height.is_infinite() { BigNum::ONE } else { BigNum::from_f64(1.0 + height - levels) };
+
+            // ln(self) - 1/e, positive when the tower grows without bound
+            let excess = self.exponent.mul_add(LN_10, -1.0 / E) + (self.exponent * LN_10_LOW - INVERSE_E_LOW);
+
+            let mut previous = BigNum::NAN;
+            let mut level = 0.0;
+            while level < levels && (excess > 0.0 || level < MAX_ITERATIONS as f64) {
+                if excess > 0.0 {
+                    if let Some((skipped, value)) = skip_plateau(result.to_f64().unwrap_or(f64::INFINITY), excess, levels - level) {
+                        previous = BigNum::NAN;
+                        result = BigNum::from_f64(value);
+                        level += skipped;
+                        continue;
+                    }
+                }
+
+                let next = self.powb(result);
+
+                if next.is_infinite() {
+                    return BigNum::INFINITY;
+                }
+
+                // The tower has converged, so adding more levels won't change it
+                if is_close(next, result) {
+                    return next;
+                }
+
+                // The tower alternates between two values (bases below e^-e), so the levels left only pick which one
+                if is_close(next, previous) {
+                    if levels.is_infinite() {
+                        return BigNum::NAN;
+                    }
+
+                    let remaining = levels - level - 1.0;
+                    return if remaining % 2.0 == 0.0 { next } else { result };
+                }
+
+                previous = result;
+                result = next;
+                level += 1.0;
+            }
+
+            return result;
+        }
+
+        /// Calculates the super-logarithm, the inverse of `tetrate`, using the same linear approximation.
+        /// Only defined for bases above e^(1/e).
+        pub fn slog(&self, base: f64) -> f64 {
+            if self.is_nan() || base.is_nan() || base <= CONVERGENCE_LIMIT {
+                return f64::NAN;
+            }
+
+            if self.is_infinite() {
+                return if self.is_negative { f64::NAN } else { f64::INFINITY };
+            }
+
+            let log10_base = base.log10();
+
+            if self.is_zero() || self.is_negative {
+                // slog(x) = slog(b^x) - 1, and 0 < b^x <= 1
+                let power = match self.to_f64() {
+                    Some(value) => base.powf(value),
+                    None => 0.0
+                };
+
+                return power - 2.0;
+            }
+
+            if self.exponent <= 0.0 {
+                // slog(x) = x - 1, for 0 < x <= 1
+                return self.to_f64().unwrap() - 1.0;
+            }
+
+            // The first logarithm is done on the exponent, since self might not fit in an f64
+            let mut result = 1.0;
+            let mut value = self.exponent / log10_base;
+
+            if value.is_infinite() {
+                value = (self.exponent.log10() - log10_base.log10()) / log10_base;
+                result += 1.0;
+            }
+
+            let mut level = 0;
+            while value > 1.0 && level < MAX_ITERATIONS {
+                value = value.log10() / log10_base;
+                result += 1.0;
+                level += 1;
+            }
+
+            return result + value - 1.0;
+        }
+
+        /// Calculates the super square root, the number y where y^y = self.
+        /// Only defined for values above e^(-1/e), and returns the principal (larger) root.
+        pub fn ssqrt(&self) -> Self {
+            if self.is_nan() || self.is_negative {
+                return BigNum::NAN;
+            }
+
+            if self.is_infinite() {
+                return BigNum::INFINITY;
+            }
+
+            if self.exponent < MIN_SELF_POWER.log10() {
+                return BigNum::NAN;
+            }
+
+            // y^y = x => y = e^W(ln(x))
+            let w = if self.exponent > 0.0 && self.exponent.ln() + LN_10.ln() >= 1.0 {
+                // ln(x) may overflow an f64, but its logarithm can't
+                BigNum::lambert_w0_from_ln(self.exponent.ln() + LN_10.ln())
+            } else {
+                BigNum::lambert_w0_f64(self.exponent * LN_10)
+            };
+
+            // log10(e^w) = w / ln(10)
+            return BigNum {
+                is_negative: false,
+                exponent: w / LN_10
+            };
+        }
+    }
+
+    fn is_close(a: BigNum, b: BigNum) -> bool {
+        return a == b || (a.is_negative == b.is_negative
+            && (a.exponent - b.exponent).abs() <= FIXED_POINT_TOLERANCE * a.exponent.abs().max(1.0));
+    }
+
+    // Just above e^(1/e), a tower spends up to about 1e8 levels next to e, so that part is skipped in one go.
+    // With ln(base) = 1/e + excess and v = x - e + 2 excess e^2, each level adds about excess e^2 + v^2 / 2e,
+    // so v follows A tan(ωn + θ) with A = e sqrt(2e excess) and ω = sqrt(excess e / 2).
+    // Returns the number of levels skipped, at most `levels_left`, and the value after them.
+    fn skip_plateau(x: f64, excess: f64, levels_left: f64) -> Option<(f64, f64)> {
+        let shift = 2.0 * excess * E * E;
+        let v = x - E + shift;
+
+        if v.abs() >= PLATEAU_WIDTH {
+            return None;
+        }
+
+        let amplitude = E * (2.0 * E * excess).sqrt();
+        let frequency = (excess * E / 2.0).sqrt();
+        let phase = (v / amplitude).atan();
+
+        // Levels until v leaves the plateau
+        let skipped = (((PLATEAU_WIDTH / amplitude).atan() - phase) / frequency).floor().min(levels_left);
+
+        if skipped < 1.0 {
+            return None;
+        }
+
+        return Some((skipped, E - shift + amplitude * (phase + frequency * skipped).tan()));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,275 @@
+mod bounds {
+    use std::{fmt, ops};
+    use std::f64::consts::LN_10;
+    use crate::bignum::{BigInterval, BigNum, Certainty};
+
+    // Every operation is assumed to be off by up to this many units in the last place of the exponent
+    const ROUNDING: f64 = 4.0 * f64::EPSILON;
+
+    // Moves the number down by the rounding error of its exponent
+    fn round_down(value: BigNum) -> BigNum {
+        if !value.is_finite() || value.is_zero() {
+            return value;
+        }
+
+        let delta = (value.exponent.abs() + 1.0) * ROUNDING;
+
+        return BigNum {
+            is_negative: value.is_negative,
+            exponent: if value.is_negative { value.exponent + delta } else { value.exponent - delta }
+        };
+    }
+
+    // Moves the number up by the rounding error of its exponent
+    fn round_up(value: BigNum) -> BigNum {
+        return -round_down(-value);
+    }
+
+    // Upper bound on the error of adding x and y: the rounding error of each term's exponent
+    // becomes a relative error of the term itself, which subtraction doesn't cancel out
+    fn sum_error(x: BigNum, y: BigNum) -> BigNum {
+        let relative_error = |value: BigNum| BigNum {
+            is_negative: false,
+            exponent: value.exponent + ((value.exponent.abs() + 1.0) * ROUNDING * LN_10).log10()
+        };
+
+        return relative_error(x) + relative_error(y);
+    }
+
+    // The smallest interval holding every candidate, widened by the rounding error
+    fn enclose(candidates: &[BigNum]) -> BigInterval {
+        if candidates.iter().any(|candidate| candidate.is_nan()) {
+            return BigInterval::NAN;
+        }
+
+        let lo = candidates.iter().fold(BigNum::INFINITY, |lo, candidate| if candidate < &lo { *candidate } else { lo });
+        let hi = candidates.iter().fold(BigNum::NEG_INFINITY, |hi, candidate| if candidate > &hi { *candidate } else { hi });
+
+        // Equal infinities don't compare, so the folds above can miss them
+        let lo = if candidates.contains(&BigNum::NEG_INFINITY) { BigNum::NEG_INFINITY } else { lo };
+        let hi = if candidates.contains(&BigNum::INFINITY) { BigNum::INFINITY } else { hi };
+
+        return BigInterval {
+            lo: round_down(lo),
+            hi: round_up(hi)
+        };
+    }
+
+    impl BigInterval {
+        pub const NAN: BigInterval = BigInterval { lo: BigNum::NAN, hi: BigNum::NAN };
+
+        pub const EVERYTHING: BigInterval = BigInterval { lo: BigNum::NEG_INFINITY, hi: BigNum::INFINITY };
+
+        /// Creates an interval between two numbers, in either order.
+        pub fn new(a: BigNum, b: BigNum) -> Self {
+            if a.is_nan() || b.is_nan() {
+                return BigInterval::NAN;
+            }
+
+            let (lo, hi) = if b < a { (b, a) } else { (a, b) };
+
+            return BigInterval { lo, hi };
+        }
+
+        /// An interval holding exactly one number.
+        pub fn exact(value: BigNum) -> Self {
+            return BigInterval::new(value, value);
+        }
+
+        /// An interval around an f64, wide enough for the rounding error of converting it.
+        pub fn from_f64(value: f64) -> Self {
+            let converted = BigNum::from_f64(value);
+
+            return BigInterval {
+                lo: round_down(converted),
+                hi: round_up(converted)
+            };
+        }
+
+        pub fn is_nan(&self) -> bool {
+            return self.lo.is_nan() || self.hi.is_nan();
+        }
+
+        pub fn contains(&self, value: &BigNum) -> bool {
+            return self.lo <= *value && *value <= self.hi
+                || (value.is_infinite() && (*value == self.lo || *value == self.hi));
+        }
+
+        pub fn contains_zero(&self) -> bool {
+            return self.contains(&BigNum::ZERO);
+        }
+
+        /// Whether every number in self is at least every number in other.
+        pub fn ge(&self, other: &BigInterval) -> Certainty {
+            return compare(self.lo >= other.hi, self.hi < other.lo, self, other);
+        }
+
+        pub fn gt(&self, other: &BigInterval) -> Certainty {
+            return compare(self.lo > other.hi, self.hi <= other.lo, self, other);
+        }
+
+        pub fn le(&self, other: &BigInterval) -> Certainty {
+            return other.ge(self);
+        }
+
+        pub fn lt(&self, other: &BigInterval) -> Certainty {
+            return other.gt(self);
+        }
+
+        pub fn recip(&self) -> Self {
+            if self.contains_zero() {
+                return BigInterval::EVERYTHING;
+            }
+
+            return enclose(&[self.hi.recip(), self.lo.recip()]);
+        }
+
+        pub fn powf(&self, exp: f64) -> Self {
+            if self.is_nan() || exp.is_nan() {
+                return BigInterval::NAN;
+            }
+
+            if self.contains_zero() && exp < 0.0 {
+                return BigInterval::EVERYTHING;
+            }
+
+            // x^p is monotonic on either side of 0, so the extremes are at the ends or at 0
+            let mut candidates = vec![self.lo.powf(exp), self.hi.powf(exp)];
+
+            if self.contains_zero() {
+                candidates.push(BigNum::ZERO.powf(exp));
+            }
+
+            return enclose(&candidates);
+        }
+
+        /// Raises a non-negative interval to the power of another interval.
+        pub fn powb(&self, exp: &BigInterval) -> Self {
+            if self.is_nan() || exp.is_nan() || self.lo.is_sign_negative() && !self.lo.is_zero() {
+                return BigInterval::NAN;
+            }
+
+            // x^y = e^(y ln(x)), and y ln(x) is bilinear in y and ln(x), so its extremes (and those of x^y) are at the corners
+            return enclose(&[
+                self.lo.powb(exp.lo),
+                self.lo.powb(exp.hi),
+                self.hi.powb(exp.lo),
+                self.hi.powb(exp.hi)
+            ]);
+        }
+
+        pub fn log10(&self) -> Self {
+            if self.is_nan() || self.lo.is_sign_negative() && !self.lo.is_zero() {
+                return BigInterval::NAN;
+            }
+
+            return enclose(&[self.lo.log10(), self.hi.log10()]);
+        }
+
+        pub fn sqrt(&self) -> Self {
+            if self.is_nan() || self.lo.is_sign_negative() && !self.lo.is_zero() {
+                return BigInterval::NAN;
+            }
+
+            return enclose(&[self.lo.sqrt(), self.hi.sqrt()]);
+        }
+    }
+
+    fn compare(definitely: bool, definitely_not: bool, a: &BigInterval, b: &BigInterval) -> Certainty {
+        if a.is_nan() || b.is_nan() {
+            return Certainty::Maybe;
+        }
+
+        if definitely {
+            return Certainty::Definitely;
+        } else if definitely_not {
+            return Certainty::DefinitelyNot;
+        }
+
+        return Certainty::Maybe;
+    }
+
+    impl Certainty {
+        pub fn is_definite(&self) -> bool {
+            return *self == Certainty::Definitely;
+        }
+    }
+
+    impl From<BigNum> for BigInterval {
+        fn from(value: BigNum) -> Self {
+            return BigInterval::exact(value);
+        }
+    }
+
+    impl ops::Neg for BigInterval {
+        type Output = BigInterval;
+
+        fn neg(self) -> Self::Output {
+            return BigInterval {
+                lo: -self.hi,
+                hi: -self.lo
+            };
+        }
+    }
+
+    impl ops::Add for BigInterval {
+        type Output = BigInterval;
+
+        fn add(self, other: Self) -> Self::Output {
+            if self.is_nan() || other.is_nan() {
+                return BigInterval::NAN;
+            }
+
+            let lo = self.lo + other.lo;
+            let hi = self.hi + other.hi;
+
+            if lo.is_nan() || hi.is_nan() {
+                return BigInterval::NAN;
+            }
+
+            return BigInterval {
+                lo: if lo.is_finite() { round_down(lo - sum_error(self.lo, other.lo)) } else { lo },
+                hi: if hi.is_finite() { round_up(hi + sum_error(self.hi, other.hi)) } else { hi }
+            };
+        }
+    }
+
+    impl ops::Sub for BigInterval {
+        type Output = BigInterval;
+
+        fn sub(self, other: Self) -> Self::Output {
+            return self + (-other);
+        }
+    }
+
+    impl ops::Mul for BigInterval {
+        type Output = BigInterval;
+
+        fn mul(self, other: Self) -> Self::Output {
+            return enclose(&[
+                self.lo * other.lo,
+                self.lo * other.hi,
+                self.hi * other.lo,
+                self.hi * other.hi
+            ]);
+        }
+    }
+
+    impl ops::Div for BigInterval {
+        type Output = BigInterval;
+
+        fn div(self, other: Self) -> Self::Output {
+            if self.is_nan() || other.is_nan() {
+                return BigInterval::NAN;
+            }
+
+            return self * other.recip();
+        }
+    }
+
+    impl fmt::Display for BigInterval {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            return write!(f, "[{}, {}]", self.lo, self.hi);
+        }
+    }
+}
\ No newline at end of file
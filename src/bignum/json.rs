@@ -0,0 +1,322 @@
+mod json_codec {
+    use crate::bignum::{BigNum, JsonError};
+
+    impl BigNum {
+        /// Writes the number as a JSON number if one reads back as exactly this number, like `1234.5` or `1e300`,
+        /// or else as a string that `from_string` can read, like `"e400.5"` or `"inf"`.
+        pub fn to_json(&self) -> String {
+            if let Some(value) = self.to_f64() {
+                // Numbers too small for an f64 would come back as 0
+                if value.is_finite() && (value != 0.0 || self.is_zero()) {
+                    // 10^exponent is only accurate to about 15 digits, so the noise after that is rounded away
+                    // unless it's needed to get this exponent back
+                    for digits in 15..=17 {
+                        let rounded: f64 = format!("{:.*e}", digits - 1, value).parse().unwrap();
+
+                        let plain = format!("{}", rounded);
+                        let scientific = format!("{:e}", rounded);
+                        let number = if scientific.len() < plain.len() { scientific } else { plain };
+
+                        if BigNum::from_string(&number) == Ok(*self) {
+                            return number;
+                        }
+                    }
+                }
+            }
+
+            return format!("\"{}\"", self.to_string_exact());
+        }
+
+        /// Reads a number written by `to_json`, either a JSON number or a string.
+        pub fn from_json(text: &str) -> Result<BigNum, JsonError> {
+            let mut reader = Reader::new(text);
+
+            let value = reader.read_value()?;
+            reader.expect_end()?;
+
+            return Ok(value);
+        }
+
+        /// Writes the numbers as a JSON array, e.g. `[1.5,"e400"]`.
+        pub fn to_json_array(values: &[BigNum]) -> String {
+            let items: Vec<String> = values.iter().map(|value| value.to_json()).collect();
+
+            return format!("[{}]", items.join(","));
+        }
+
+        pub fn from_json_array(text: &str) -> Result<Vec<BigNum>, JsonError> {
+            let mut reader = Reader::new(text);
+            let mut values = Vec::new();
+
+            reader.expect(b'[')?;
+
+            if !reader.consume(b']') {
+                loop {
+                    values.push(reader.read_value()?);
+
+                    if reader.consume(b']') {
+                        break;
+                    }
+
+                    reader.expect(b',')?;
+                }
+            }
+
+            reader.expect_end()?;
+
+            return Ok(values);
+        }
+
+        /// Writes the entries as a JSON object, e.g. `{"gold":1.5,"mana":"e400"}`.
+        pub fn to_json_object<'a>(entries: impl IntoIterator<Item = (&'a str, &'a BigNum)>) -> String {
+            let items: Vec<String> = entries.into_iter()
+                .map(|(key, value)| format!("{}:{}", escape_string(key), value.to_json()))
+                .collect();
+
+            return format!("{{{}}}", items.join(","));
+        }
+
+        /// Reads a JSON object of numbers, keeping the entries in the order they were written.
+        pub fn from_json_object(text: &str) -> Result<Vec<(String, BigNum)>, JsonError> {
+            let mut reader = Reader::new(text);
+            let mut entries = Vec::new();
+
+            reader.expect(b'{')?;
+
+            if !reader.consume(b'}') {
+                loop {
+                    reader.skip_whitespace();
+                    let key = reader.read_string()?;
+
+                    reader.expect(b':')?;
+                    entries.push((key, reader.read_value()?));
+
+                    if reader.consume(b'}') {
+                        break;
+                    }
+
+                    reader.expect(b',')?;
+                }
+            }
+
+            reader.expect_end()?;
+
+            return Ok(entries);
+        }
+    }
+
+    fn escape_string(value: &str) -> String {
+        let mut escaped = String::with_capacity(value.len() + 2);
+        escaped.push('"');
+
+        for character in value.chars() {
+            match character {
+                '"' => escaped.push_str("\\\""),
+                '\\' => escaped.push_str("\\\\"),
+                '\n' => escaped.push_str("\\n"),
+                '\r' => escaped.push_str("\\r"),
+                '\t' => escaped.push_str("\\t"),
+                c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+                c => escaped.push(c)
+            }
+        }
+
+        escaped.push('"');
+
+        return escaped;
+    }
+
+    // Reads JSON one byte at a time, keeping track of the position for error messages
+    struct Reader<'a> {
+        text: &'a str,
+        position: usize
+    }
+
+    impl<'a> Reader<'a> {
+        fn new(text: &'a str) -> Self {
+            return Reader { text, position: 0 };
+        }
+
+        fn peek(&self) -> Option<u8> {
+            return self.text.as_bytes().get(self.position).copied();
+        }
+
+        fn skip_whitespace(&mut self) {
+            while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+                self.position += 1;
+            }
+        }
+
+        fn unexpected(&self) -> JsonError {
+            return match self.peek() {
+                Some(_) => JsonError::UnexpectedCharacter { position: self.position },
+                None => JsonError::UnexpectedEnd
+            };
+        }
+
+        // Skips the byte if it's next, ignoring whitespace before it
+        fn consume(&mut self, byte: u8) -> bool {
+            self.skip_whitespace();
+
+            if self.peek() == Some(byte) {
+                self.position += 1;
+                return true;
+            }
+
+            return false;
+        }
+
+        fn expect(&mut self, byte: u8) -> Result<(), JsonError> {
+            if self.consume(byte) {
+                return Ok(());
+            }
+
+            return Err(self.unexpected());
+        }
+
+        fn expect_end(&mut self) -> Result<(), JsonError> {
+            self.skip_whitespace();
+
+            if self.position != self.text.len() {
+                return Err(JsonError::TrailingCharacters { position: self.position });
+            }
+
+            return Ok(());
+        }
+
+        fn read_value(&mut self) -> Result<BigNum, JsonError> {
+            self.skip_whitespace();
+            let start = self.position;
+
+            let text = match self.peek() {
+                Some(b'"') => self.read_string()?,
+                Some(b'-' | b'0'..=b'9') => self.read_number()?,
+                _ => return Err(self.unexpected())
+            };
+
+            return BigNum::from_string(&text).map_err(|error| JsonError::InvalidNumber { position: start, error });
+        }
+
+        // Reads a number in JSON's grammar, -?(0|[1-9][0-9]*)(.[0-9]+)?([eE][+-]?[0-9]+)?
+        // from_string reads more than that (like 'e5' or '1e2e3'), but only inside strings
+        fn read_number(&mut self) -> Result<String, JsonError> {
+            let start = self.position;
+
+            if self.peek() == Some(b'-') {
+                self.position += 1;
+            }
+
+            if self.peek() == Some(b'0') {
+                self.position += 1;
+            } else {
+                self.read_digits()?;
+            }
+
+            if self.peek() == Some(b'.') {
+                self.position += 1;
+                self.read_digits()?;
+            }
+
+            if matches!(self.peek(), Some(b'e' | b'E')) {
+                self.position += 1;
+
+                if matches!(self.peek(), Some(b'-' | b'+')) {
+                    self.position += 1;
+                }
+
+                self.read_digits()?;
+            }
+
+            return Ok(self.text[start..self.position].to_string());
+        }
+
+        // Reads one or more digits
+        fn read_digits(&mut self) -> Result<(), JsonError> {
+            if !matches!(self.peek(), Some(b'0'..=b'9')) {
+                return Err(self.unexpected());
+            }
+
+            while matches!(self.peek(), Some(b'0'..=b'9')) {
+                self.position += 1;
+            }
+
+            return Ok(());
+        }
+
+        fn read_string(&mut self) -> Result<String, JsonError> {
+            if self.peek() != Some(b'"') {
+                return Err(self.unexpected());
+            }
+
+            self.position += 1;
+            let mut value = String::new();
+
+            loop {
+                let rest = &self.text[self.position..];
+                let character = rest.chars().next().ok_or(JsonError::UnexpectedEnd)?;
+                self.position += character.len_utf8();
+
+                match character {
+                    '"' => return Ok(value),
+                    '\\' => value.push(self.read_escape()?),
+                    c if (c as u32) < 0x20 => return Err(JsonError::UnexpectedCharacter { position: self.position - 1 }),
+                    c => value.push(c)
+                }
+            }
+        }
+
+        // Reads the part after a backslash
+        fn read_escape(&mut self) -> Result<char, JsonError> {
+            let start = self.position - 1;
+            let byte = self.peek().ok_or(JsonError::UnexpectedEnd)?;
+            self.position += 1;
+
+            return match byte {
+                b'"' => Ok('"'),
+                b'\\' => Ok('\\'),
+                b'/' => Ok('/'),
+                b'b' => Ok('\u{8}'),
+                b'f' => Ok('\u{c}'),
+                b'n' => Ok('\n'),
+                b'r' => Ok('\r'),
+                b't' => Ok('\t'),
+                b'u' => {
+                    let high = self.read_hex(start)?;
+
+                    // Characters outside the basic plane are written as a surrogate pair, like '😀'
+                    if (0xD800..0xDC00).contains(&high) {
+                        if !self.text[self.position..].starts_with("\\u") {
+                            return Err(JsonError::InvalidEscape { position: start });
+                        }
+
+                        self.position += 2;
+                        let low = self.read_hex(start)?;
+
+                        if !(0xDC00..0xE000).contains(&low) {
+                            return Err(JsonError::InvalidEscape { position: start });
+                        }
+
+                        return char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+                            .ok_or(JsonError::InvalidEscape { position: start });
+                    }
+
+                    char::from_u32(high).ok_or(JsonError::InvalidEscape { position: start })
+                },
+                _ => Err(JsonError::InvalidEscape { position: start })
+            };
+        }
+
+        fn read_hex(&mut self, escape_start: usize) -> Result<u32, JsonError> {
+            let digits = self.text.get(self.position..(self.position + 4))
+                .ok_or(JsonError::InvalidEscape { position: escape_start })?;
+
+            if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(JsonError::InvalidEscape { position: escape_start });
+            }
+
+            self.position += 4;
+
+            return Ok(u32::from_str_radix(digits, 16).unwrap());
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,640 @@
+mod layer {
+    use std::{cmp::Ordering, fmt, ops};
+    use crate::bignum::{BigNum, LayeredNum};
+
+    // log10(f64::MAX), the largest magnitude that can be moved down a layer
+    const LAYER_THRESHOLD: f64 = 308.25471555991675;
+
+    impl LayeredNum {
+        pub const ZERO: LayeredNum = LayeredNum {
+            is_negative: false,
+            layer: 0,
+            magnitude: f64::NEG_INFINITY
+        };
+
+        pub const ONE: LayeredNum = LayeredNum {
+            is_negative: false,
+            layer: 0,
+            magnitude: 0.0
+        };
+
+        pub const INFINITY: LayeredNum = LayeredNum {
+            is_negative: false,
+            layer: 0,
+            magnitude: f64::INFINITY
+        };
+
+        pub const NEG_INFINITY: LayeredNum = LayeredNum {
+            is_negative: true,
+            layer: 0,
+            magnitude: f64::INFINITY
+        };
+
+        pub const NAN: LayeredNum = LayeredNum {
+            is_negative: false,
+            layer: 0,
+            magnitude: f64::NAN
+        };
+    }
+
+    // Conversions
+    impl LayeredNum {
+        pub fn from_layers(is_negative: bool, layer: u32, magnitude: f64) -> LayeredNum {
+            return LayeredNum {
+                is_negative,
+                layer,
+                magnitude
+            }.normalize();
+        }
+
+        pub fn from_bignum(value: BigNum) -> LayeredNum {
+            return LayeredNum {
+                is_negative: value.is_negative,
+                layer: 0,
+                magnitude: value.exponent
+            };
+        }
+
+        pub fn from_f64(value: f64) -> LayeredNum {
+            return LayeredNum::from_bignum(BigNum::from_f64(value));
+        }
+
+        /// Returns `None` if the number is too large or too small to fit in a `BigNum`.
+        pub fn to_bignum(&self) -> Option<BigNum> {
+            let normalized = self.normalize();
+
+            if normalized.layer > 0 {
+                return None;
+            }
+
+            return Some(BigNum {
+                is_negative: normalized.is_negative,
+                exponent: normalized.magnitude
+            });
+        }
+
+        pub fn to_f64(&self) -> Option<f64> {
+            return self.to_bignum()?.to_f64();
+        }
+
+        /// Moves the magnitude down as many layers as possible without overflowing.
+        pub fn normalize(&self) -> LayeredNum {
+            if self.magnitude.is_nan() {
+                return LayeredNum::NAN;
+            }
+
+            let mut result = *self;
+
+            while result.layer > 0 && result.magnitude.abs() <= LAYER_THRESHOLD {
+                // 10^10^x = 10^(10^x), so the layer below has a magnitude of 10^x
+                let magnitude = 10_f64.powf(result.magnitude.abs());
+
+                result.layer -= 1;
+                result.magnitude = if result.magnitude < 0.0 { -magnitude } else { magnitude };
+            }
+
+            if result.layer > 0 && result.magnitude.is_infinite() {
+                return LayeredNum {
+                    is_negative: result.is_negative,
+                    layer: 0,
+                    magnitude: result.magnitude
+                };
+            }
+
+            return result;
+        }
+    }
+
+    impl From<BigNum> for LayeredNum {
+        fn from(value: BigNum) -> Self {
+            return LayeredNum::from_bignum(value);
+        }
+    }
+
+    impl From<f64> for LayeredNum {
+        fn from(value: f64) -> Self {
+            return LayeredNum::from_f64(value);
+        }
+    }
+
+    // Classification
+    impl LayeredNum {
+        pub fn is_nan(&self) -> bool {
+            return self.magnitude.is_nan();
+        }
+
+        pub fn is_infinite(&self) -> bool {
+            return self.magnitude.is_infinite() && self.magnitude.is_sign_positive();
+        }
+
+        pub fn is_finite(&self) -> bool {
+            return !self.is_infinite() && !self.is_nan();
+        }
+
+        pub fn is_zero(&self) -> bool {
+            return self.magnitude.is_infinite() && self.magnitude.is_sign_negative();
+        }
+
+        pub fn is_sign_positive(&self) -> bool {
+            return !self.is_negative;
+        }
+
+        pub fn is_sign_negative(&self) -> bool {
+            return self.is_negative;
+        }
+
+        // Orders the absolute values by tower height, from zero up to infinity
+        fn tier(&self) -> i64 {
+            if self.layer == 0 {
+                if self.is_zero() {
+                    return i64::MIN;
+                } else if self.is_infinite() {
+                    return i64::MAX;
+                }
+
+                return 0;
+            }
+
+            // Negative magnitudes are reciprocals, which get smaller the higher the layer
+            if self.magnitude < 0.0 {
+                return -(self.layer as i64);
+            }
+
+            return self.layer as i64;
+        }
+
+        fn cmp_abs(&self, other: &LayeredNum) -> Ordering {
+            let a = self.normalize();
+            let b = other.normalize();
+
+            return match a.tier().cmp(&b.tier()) {
+                Ordering::Equal => a.magnitude.partial_cmp(&b.magnitude).unwrap_or(Ordering::Equal),
+                ordering => ordering
+            };
+        }
+    }
+
+    // Moving between layers
+    impl LayeredNum {
+        // log10(|x|), which is exactly one layer below x
+        fn log10_abs(&self) -> LayeredNum {
+            let normalized = self.normalize();
+
+            if normalized.layer == 0 {
+                return LayeredNum::from_f64(normalized.magnitude);
+            }
+
+            return LayeredNum {
+                is_negative: normalized.magnitude < 0.0,
+                layer: normalized.layer - 1,
+                magnitude: normalized.magnitude.abs()
+            };
+        }
+
+        // The inverse of log10_abs: 10^exponent, with the given sign
+        fn from_log10_abs(exponent: LayeredNum, is_negative: bool) -> LayeredNum {
+            let exponent = exponent.normalize();
+
+            if exponent.is_nan() {
+                return LayeredNum::NAN;
+            }
+
+            if exponent.layer == 0 {
+                if exponent.is_infinite() {
+                    // 10^Infinity = Infinity, 10^-Infinity = 0
+                    return LayeredNum {
+                        is_negative,
+                        layer: 0,
+                        magnitude: if exponent.is_negative { f64::NEG_INFINITY } else { f64::INFINITY }
+                    };
+                }
+
+                if exponent.magnitude <= LAYER_THRESHOLD {
+                    // The exponent fits in an f64, so the result stays on layer 0
+                    let magnitude = 10_f64.powf(exponent.magnitude);
+
+                    return LayeredNum {
+                        is_negative,
+                        layer: 0,
+                        magnitude: if exponent.is_negative { -magnitude } else { magnitude }
+                    };
+                }
+            } else if exponent.magnitude < 0.0 {
+                // The exponent is too close to zero to make 10^exponent differ from 1
+                return LayeredNum {
+                    is_negative,
+                    layer: 0,
+                    magnitude: 0.0
+                };
+            }
+
+            return match exponent.layer.checked_add(1) {
+                Some(layer) => LayeredNum {
+                    is_negative,
+                    layer,
+                    magnitude: if exponent.is_negative { -exponent.magnitude } else { exponent.magnitude }
+                },
+                None => LayeredNum {
+                    is_negative,
+                    layer: 0,
+                    magnitude: if exponent.is_negative { f64::NEG_INFINITY } else { f64::INFINITY }
+                }
+            };
+        }
+    }
+
+    impl ops::Add for LayeredNum {
+        type Output = LayeredNum;
+
+        fn add(self, other: Self) -> Self::Output {
+            if self.is_nan() || other.is_nan() {
+                return LayeredNum::NAN;
+            }
+
+            if self.is_infinite() || other.is_infinite() {
+                // Infinity - Infinity = NaN
+                if self.is_infinite() && other.is_infinite() && self.is_negative != other.is_negative {
+                    return LayeredNum::NAN;
+                }
+
+                // Infinity + x = Infinity
+                return if self.is_infinite() {
+                    self
+                } else {
+                    other
+                };
+            }
+
+            let a = self.normalize();
+            let b = other.normalize();
+
+            if let (Some(a), Some(b)) = (a.to_bignum(), b.to_bignum()) {
+                return LayeredNum::from_bignum(a + b);
+            }
+
+            // At least one of the numbers is outside of BigNum's range,
+            // so the smaller one is far too small to change the larger one
+            return match a.cmp_abs(&b) {
+                Ordering::Greater => a,
+                Ordering::Less => b,
+                Ordering::Equal => if a.is_negative == b.is_negative {
+                    // x + x = 2x, which is indistinguishable from x at this size
+                    a
+                } else {
+                    // x - x = 0
+                    LayeredNum::ZERO
+                }
+            };
+        }
+    }
+    impl ops::Add<f64> for LayeredNum {
+        type Output = LayeredNum;
+
+        fn add(self, other: f64) -> Self::Output {
+            return self + LayeredNum::from_f64(other);
+        }
+    }
+    impl ops::Add<LayeredNum> for f64 {
+        type Output = LayeredNum;
+
+        fn add(self, other: LayeredNum) -> Self::Output {
+            return LayeredNum::from_f64(self) + other;
+        }
+    }
+
+    impl ops::AddAssign for LayeredNum {
+        fn add_assign(&mut self, other: Self) {
+            *self = *self + other;
+        }
+    }
+    impl ops::AddAssign<f64> for LayeredNum {
+        fn add_assign(&mut self, other: f64) {
+            *self = *self + LayeredNum::from_f64(other);
+        }
+    }
+
+    impl ops::Neg for LayeredNum {
+        type Output = LayeredNum;
+
+        fn neg(self) -> Self::Output {
+            return LayeredNum {
+                is_negative: !self.is_negative,
+                layer: self.layer,
+                magnitude: self.magnitude
+            };
+        }
+    }
+
+    impl ops::Sub for LayeredNum {
+        type Output = LayeredNum;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            return self + -rhs;
+        }
+    }
+    impl ops::Sub<f64> for LayeredNum {
+        type Output = LayeredNum;
+
+        fn sub(self, other: f64) -> Self::Output {
+            return self - LayeredNum::from_f64(other);
+        }
+    }
+    impl ops::Sub<LayeredNum> for f64 {
+        type Output = LayeredNum;
+
+        fn sub(self, other: LayeredNum) -> Self::Output {
+            return LayeredNum::from_f64(self) - other;
+        }
+    }
+
+    impl ops::SubAssign for LayeredNum {
+        fn sub_assign(&mut self, other: Self) {
+            *self = *self - other;
+        }
+    }
+    impl ops::SubAssign<f64> for LayeredNum {
+        fn sub_assign(&mut self, other: f64) {
+            *self = *self - LayeredNum::from_f64(other);
+        }
+    }
+
+    impl ops::Mul for LayeredNum {
+        type Output = LayeredNum;
+
+        fn mul(self, rhs: Self) -> Self::Output {
+            if self.is_nan() || rhs.is_nan() {
+                return LayeredNum::NAN;
+            }
+
+            let is_negative = self.is_negative != rhs.is_negative;
+
+            if self.is_infinite() || rhs.is_infinite() {
+                // Infinity * any = Infinity
+                return LayeredNum {
+                    is_negative,
+                    layer: 0,
+                    magnitude: f64::INFINITY
+                };
+            }
+
+            if self.is_zero() || rhs.is_zero() {
+                return LayeredNum {
+                    is_negative,
+                    layer: 0,
+                    magnitude: f64::NEG_INFINITY
+                };
+            }
+
+            let a = self.normalize();
+            let b = rhs.normalize();
+
+            if a.layer == 0 && b.layer == 0 {
+                // log10(10^a * 10^b) = a + b, for all real a, b
+                let magnitude = a.magnitude + b.magnitude;
+
+                if magnitude.is_finite() {
+                    return LayeredNum {
+                        is_negative,
+                        layer: 0,
+                        magnitude
+                    };
+                }
+            }
+
+            // Same as above, but with the exponents one layer down
+            return LayeredNum::from_log10_abs(a.log10_abs() + b.log10_abs(), is_negative);
+        }
+    }
+    impl ops::Mul<f64> for LayeredNum {
+        type Output = LayeredNum;
+
+        fn mul(self, other: f64) -> Self::Output {
+            return self * LayeredNum::from_f64(other);
+        }
+    }
+    impl ops::Mul<LayeredNum> for f64 {
+        type Output = LayeredNum;
+
+        fn mul(self, other: LayeredNum) -> Self::Output {
+            return LayeredNum::from_f64(self) * other;
+        }
+    }
+
+    impl ops::MulAssign for LayeredNum {
+        fn mul_assign(&mut self, other: Self) {
+            *self = *self * other;
+        }
+    }
+    impl ops::MulAssign<f64> for LayeredNum {
+        fn mul_assign(&mut self, other: f64) {
+            *self = *self * LayeredNum::from_f64(other);
+        }
+    }
+
+    impl ops::Div for LayeredNum {
+        type Output = LayeredNum;
+
+        fn div(self, rhs: Self) -> Self::Output {
+            if self.is_nan() || rhs.is_nan() {
+                return LayeredNum::NAN;
+            }
+
+            if self.is_infinite() || rhs.is_infinite() {
+                // Infinity / Infinity = NaN
+                if self.is_infinite() && rhs.is_infinite() {
+                    return LayeredNum::NAN;
+                }
+
+                // Infinity / finite = Infinity
+                if self.is_infinite() {
+                    return LayeredNum {
+                        is_negative: self.is_negative != rhs.is_negative,
+                        layer: 0,
+                        magnitude: f64::INFINITY
+                    };
+                }
+
+                // finite / Infinity = 0
+                return LayeredNum::ZERO;
+            }
+
+            // 0 / 0 = NaN
+            if self.is_zero() && rhs.is_zero() {
+                return LayeredNum::NAN;
+            }
+
+            // a / b = a * (1 / b)
+            return self * rhs.recip();
+        }
+    }
+    impl ops::Div<f64> for LayeredNum {
+        type Output = LayeredNum;
+
+        fn div(self, other: f64) -> Self::Output {
+            return self / LayeredNum::from_f64(other);
+        }
+    }
+    impl ops::Div<LayeredNum> for f64 {
+        type Output = LayeredNum;
+
+        fn div(self, other: LayeredNum) -> Self::Output {
+            return LayeredNum::from_f64(self) / other;
+        }
+    }
+
+    impl ops::DivAssign for LayeredNum {
+        fn div_assign(&mut self, other: Self) {
+            *self = *self / other;
+        }
+    }
+    impl ops::DivAssign<f64> for LayeredNum {
+        fn div_assign(&mut self, other: f64) {
+            *self = *self / LayeredNum::from_f64(other);
+        }
+    }
+
+    impl LayeredNum {
+        pub fn recip(&self) -> Self {
+            // 1 / 10^x = 10^-x, on every layer
+            return LayeredNum {
+                is_negative: self.is_negative,
+                layer: self.layer,
+                magnitude: -self.magnitude
+            };
+        }
+
+        pub fn abs(&self) -> Self {
+            return LayeredNum {
+                is_negative: false,
+                layer: self.layer,
+                magnitude: self.magnitude
+            };
+        }
+    }
+
+    // Exponentiation and logarithms
+    impl LayeredNum {
+        pub fn powb(&self, exp: LayeredNum) -> Self {
+            if self.is_nan() || exp.is_nan() {
+                return LayeredNum::NAN;
+            }
+
+            // Only integers below 2^53 can be odd, everything above that is even
+            let is_odd = match exp.to_bignum() {
+                Some(exp) => exp % 2.0 != BigNum::ZERO,
+                None => false
+            };
+
+            if self.is_zero() {
+                if exp.is_sign_positive() {
+                    return LayeredNum::ZERO;
+                } else if exp.is_sign_negative() {
+                    return LayeredNum::NAN;
+                } else {
+                    return LayeredNum::ONE;
+                }
+            }
+
+            if self.is_infinite() {
+                if exp.is_sign_positive() {
+                    return LayeredNum {
+                        is_negative: self.is_negative && is_odd,
+                        layer: 0,
+                        magnitude: f64::INFINITY
+                    };
+                } else if exp.is_sign_negative() {
+                    return LayeredNum::ZERO;
+                } else {
+                    return LayeredNum::NAN;
+                }
+            }
+
+            let is_negative = self.is_negative && is_odd;
+            let base = self.normalize();
+
+            if base.layer == 0 {
+                if let Some(exp) = exp.to_f64() {
+                    // log10((10^a)^b) = a * b
+                    let magnitude = base.magnitude * exp;
+
+                    if magnitude.is_finite() {
+                        return LayeredNum {
+                            is_negative,
+                            layer: 0,
+                            magnitude
+                        };
+                    }
+                }
+            }
+
+            return LayeredNum::from_log10_abs(base.log10_abs() * exp, is_negative);
+        }
+
+        pub fn powf(&self, exp: f64) -> Self {
+            return self.powb(LayeredNum::from_f64(exp));
+        }
+
+        pub fn pow10(&self) -> Self {
+            return LayeredNum::from_log10_abs(*self, false);
+        }
+
+        pub fn log10(&self) -> Self {
+            if self.is_negative {
+                return LayeredNum::NAN;
+            }
+
+            return self.log10_abs();
+        }
+
+        pub fn abs_log10(&self) -> Self {
+            return self.log10_abs();
+        }
+    }
+
+    impl PartialOrd for LayeredNum {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            // NaNs are uncomparable
+            if self.is_nan() || other.is_nan() {
+                return None;
+            }
+
+            // Infinities of the same sign are uncomparable
+            if self.is_infinite() && other.is_infinite() && self.is_negative == other.is_negative {
+                return None;
+            }
+
+            // Handle different signs
+            if self.is_negative != other.is_negative {
+                return Some(if self.is_negative { Ordering::Less } else { Ordering::Greater });
+            }
+
+            // Below this, signs are equal
+            let ordering = self.cmp_abs(other);
+
+            return Some(if self.is_negative { ordering.reverse() } else { ordering });
+        }
+    }
+
+    impl fmt::Display for LayeredNum {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let normalized = self.normalize();
+
+            if let Some(value) = normalized.to_bignum() {
+                return write!(f, "{}", value);
+            }
+
+            let sign = if normalized.is_negative { "-" } else { "" };
+
+            if normalized.layer <= 3 {
+                // Small towers are written out, e.g. 'ee1e400'
+                return write!(f, "{}e{}", sign, normalized.log10_abs());
+            }
+
+            // Tall towers get their height written instead, e.g. '(e^5)1e400'
+            return write!(f, "{}(e^{}){}", sign, normalized.layer, BigNum {
+                is_negative: normalized.magnitude < 0.0,
+                exponent: normalized.magnitude.abs()
+            });
+        }
+    }
+}
\ No newline at end of file
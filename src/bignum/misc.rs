@@ -47,17 +47,89 @@ mod misc {
     }
 
     // Implementations for Display
+    // The sign is left out of the formatted number and added back by `pad_integral`,
+    // which also takes care of the '+' flag, width, fill and alignment.
+    // Negative zero is printed as '0', like to_string_sci does
     impl fmt::Display for BigNum {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            write!(f, "{}",
-                if self.exponent < 100.0 {
-                    self.to_string_sci(2)
-                } else if self.exponent < 1e6 {
-                    self.to_string_sci(0)
+            if self.is_nan() {
+                return f.pad("NaN");
+            }
+
+            let abs = BigNum::from_exponent(self.exponent, false);
+
+            let formatted = if f.alternate() {
+                // Verbose form, e.g. '1.23e45 (e45.090)'
+                if abs.is_finite() && !abs.is_zero() {
+                    format!(
+                        "{} ({})",
+                        abs.to_string_sci(f.precision().unwrap_or(2)),
+                        abs.to_string_log(f.precision().unwrap_or(3))
+                    )
                 } else {
-                    self.to_string_log(3)
+                    abs.to_string_sci(f.precision().unwrap_or(2))
+                }
+            } else if let Some(precision) = f.precision() {
+                if abs.exponent < 1e6 {
+                    abs.to_string_sci(precision)
+                } else {
+                    abs.to_string_log(precision)
+                }
+            } else if abs.exponent < 100.0 {
+                abs.to_string_sci(2)
+            } else if abs.exponent < 1e6 {
+                abs.to_string_sci(0)
+            } else {
+                abs.to_string_log(3)
+            };
+
+            return f.pad_integral(!self.is_negative || self.is_zero(), "", &formatted);
+        }
+    }
+
+    impl BigNum {
+        // Shared by LowerExp and UpperExp, formats like f64 does, e.g. '1.23e45'
+        fn fmt_exp(&self, f: &mut fmt::Formatter<'_>, separator: char) -> fmt::Result {
+            if self.is_nan() {
+                return f.pad("NaN");
+            }
+
+            let formatted = if self.is_infinite() {
+                "inf".into()
+            } else if self.is_zero() {
+                format!("{:.*}{}0", f.precision().unwrap_or(0), 0.0, separator)
+            } else {
+                let mut exponent = self.exponent.floor();
+                let mut mantissa = 10_f64.powf(self.exponent - exponent);
+
+                match f.precision() {
+                    Some(precision) => {
+                        // Rounding can push the mantissa up to 10
+                        let scale = 10_f64.powi(precision.min(17) as i32);
+                        if (mantissa * scale).round() / scale >= 10.0 {
+                            mantissa /= 10.0;
+                            exponent += 1.0;
+                        }
+
+                        format!("{:.*}{}{:.0}", precision, mantissa, separator, exponent)
+                    },
+                    None => format!("{}{}{:.0}", mantissa, separator, exponent)
                 }
-            )
+            };
+
+            return f.pad_integral(!self.is_negative || self.is_zero(), "", &formatted);
+        }
+    }
+
+    impl fmt::LowerExp for BigNum {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            return self.fmt_exp(f, 'e');
+        }
+    }
+
+    impl fmt::UpperExp for BigNum {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            return self.fmt_exp(f, 'E');
         }
     }
 }
\ No newline at end of file
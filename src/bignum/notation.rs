@@ -0,0 +1,180 @@
+pub use self::formats::{
+    find_notation, Engineering, HyperE, Infinity, Letters, Logarithm, Notation, Scientific, StandardSuffix, BUILTIN_NOTATIONS
+};
+
+mod formats {
+    use crate::bignum::BigNum;
+
+    // log10(2^1024), the point where an f64 overflows
+    const INFINITY_LOG10: f64 = 308.25471555991675;
+
+    // Exponents past this lose their integer precision, so the group count can't be named exactly
+    const MAX_EXACT_EXPONENT: f64 = 9007199254740992.0;
+
+    /// A way of writing a BigNum as text, e.g. scientific ('1.23e45') or suffixes ('1.23 Qa').
+    pub trait Notation {
+        /// Name of the notation, as used by `find_notation`.
+        fn name(&self) -> &'static str;
+
+        /// Formats a positive, finite, non-zero number.
+        fn format_positive(&self, value: BigNum, precision: usize) -> String;
+
+        /// Formats any number, handling NaN, zero, infinity and the sign before calling `format_positive`.
+        fn format(&self, value: &BigNum, precision: usize) -> String {
+            if value.is_nan() {
+                return "NaN".into();
+            }
+
+            if value.is_zero() {
+                return format!("{:.*}", precision, 0.0);
+            }
+
+            let sign = if value.is_negative { "-" } else { "" };
+
+            if value.is_infinite() {
+                return format!("{}inf", sign);
+            }
+
+            let positive = BigNum::from_exponent(value.exponent, false);
+
+            return format!("{}{}", sign, self.format_positive(positive, precision));
+        }
+    }
+
+    /// '1.23e45', see `BigNum::to_string_sci`.
+    pub struct Scientific;
+
+    /// '12.30e45', see `BigNum::to_string_eng`.
+    pub struct Engineering;
+
+    /// 'e45.09', see `BigNum::to_string_log`.
+    pub struct Logarithm;
+
+    /// '1.23 Qa', see `BigNum::to_string_suffix`.
+    pub struct StandardSuffix;
+
+    /// '1.23a' for thousands, then 'b' for millions, up to 'z', then 'aa', 'ab', 'ac', ...
+    pub struct Letters;
+
+    /// 'E45.09' for 10^45.09, and 'E1.23#2' for 10^10^1.23.
+    pub struct HyperE;
+
+    /// '1.23∞', counting in powers of 2^1024, the largest f64.
+    pub struct Infinity;
+
+    pub const BUILTIN_NOTATIONS: [&dyn Notation; 7] = [
+        &Scientific,
+        &Engineering,
+        &Logarithm,
+        &StandardSuffix,
+        &Letters,
+        &HyperE,
+        &Infinity
+    ];
+
+    /// Finds a built-in notation by name, ignoring case.
+    pub fn find_notation(name: &str) -> Option<&'static dyn Notation> {
+        return BUILTIN_NOTATIONS.into_iter().find(|notation| notation.name().eq_ignore_ascii_case(name));
+    }
+
+    impl Notation for Scientific {
+        fn name(&self) -> &'static str {
+            return "scientific";
+        }
+
+        fn format_positive(&self, value: BigNum, precision: usize) -> String {
+            return value.to_string_sci(precision);
+        }
+    }
+
+    impl Notation for Engineering {
+        fn name(&self) -> &'static str {
+            return "engineering";
+        }
+
+        fn format_positive(&self, value: BigNum, precision: usize) -> String {
+            return value.to_string_eng(precision);
+        }
+    }
+
+    impl Notation for Logarithm {
+        fn name(&self) -> &'static str {
+            return "logarithm";
+        }
+
+        fn format_positive(&self, value: BigNum, precision: usize) -> String {
+            return value.to_string_log(precision);
+        }
+    }
+
+    impl Notation for StandardSuffix {
+        fn name(&self) -> &'static str {
+            return "standard";
+        }
+
+        fn format_positive(&self, value: BigNum, precision: usize) -> String {
+            return value.to_string_suffix(precision);
+        }
+    }
+
+    impl Notation for Letters {
+        fn name(&self) -> &'static str {
+            return "letters";
+        }
+
+        fn format_positive(&self, value: BigNum, precision: usize) -> String {
+            if value.exponent < 3.0 || value.exponent >= MAX_EXACT_EXPONENT {
+                return value.to_string_sci(precision);
+            }
+
+            let (mantissa, group) = value.split_groups(3.0, precision);
+
+            // Bijective base 26: 1 is 'a', 26 is 'z', 27 is 'aa'
+            let mut letters = Vec::new();
+            let mut remaining = group as u64;
+
+            while remaining > 0 {
+                remaining -= 1;
+                letters.push(b'a' + (remaining % 26) as u8);
+                remaining /= 26;
+            }
+
+            letters.reverse();
+
+            return format!("{:.*}{}", precision, mantissa, String::from_utf8(letters).unwrap());
+        }
+    }
+
+    impl Notation for HyperE {
+        fn name(&self) -> &'static str {
+            return "hyper-e";
+        }
+
+        fn format_positive(&self, value: BigNum, precision: usize) -> String {
+            if value.exponent < 1.0 {
+                return value.to_string_sci(precision);
+            }
+
+            if value.exponent < 1e10 {
+                return format!("E{:.*}", precision, value.exponent);
+            }
+
+            // E1.23#2 = 10^10^1.23
+            return format!("E{:.*}#2", precision, value.exponent.log10());
+        }
+    }
+
+    impl Notation for Infinity {
+        fn name(&self) -> &'static str {
+            return "infinity";
+        }
+
+        fn format_positive(&self, value: BigNum, precision: usize) -> String {
+            if value.exponent < INFINITY_LOG10 {
+                return value.to_string_sci(precision);
+            }
+
+            return format!("{:.*}∞", precision, value.exponent / INFINITY_LOG10);
+        }
+    }
+}
\ No newline at end of file
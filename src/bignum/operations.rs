@@ -546,17 +546,48 @@ mod ops {
             }
         }
 
+        /// Calculates e^self, straight in the exponent so it stays accurate for huge and tiny arguments.
         pub fn exp(&self) -> Self {
-            let e = BigNum::from_f64(std::f64::consts::E);
-            return e.powb(*self);
+            return exp_with_base(self, std::f64::consts::LOG10_E);
         }
 
         pub fn exp2(&self) -> Self {
-            let two = BigNum {
-                is_negative: false,
-                exponent: std::f64::consts::LOG10_2
-            };
-            return two.powb(*self);
+            return exp_with_base(self, std::f64::consts::LOG10_2);
+        }
+
+        pub fn exp10(&self) -> Self {
+            return exp_with_base(self, 1.0);
+        }
+
+        /// Calculates e^self - 1, which keeps its precision for arguments close to 0.
+        pub fn exp_m1(&self) -> Self {
+            if self.is_nan() || self.is_zero() {
+                return *self;
+            }
+
+            if self.exponent < 0.0 {
+                return with_ratio(self, f64::exp_m1);
+            }
+
+            return self.exp() - BigNum::ONE;
+        }
+
+        /// Calculates ln(1 + self), which keeps its precision for arguments close to 0.
+        pub fn ln_1p(&self) -> Self {
+            if self.is_nan() || self.is_zero() {
+                return *self;
+            }
+
+            if self.exponent < 0.0 {
+                return with_ratio(self, f64::ln_1p);
+            }
+
+            // ln(0) at -1, and undefined below that
+            if self.is_negative {
+                return if self.exponent == 0.0 { BigNum::NEG_INFINITY } else { BigNum::NAN };
+            }
+
+            return scaled_exponent(&(*self + BigNum::ONE), std::f64::consts::LN_10);
         }
 
         pub fn log10(&self) -> Self {
@@ -568,10 +599,7 @@ mod ops {
         }
 
         pub fn abs_log10(&self) -> Self {
-            return BigNum {
-                is_negative: false,
-                exponent: self.exponent.log10()
-            };
+            return scaled_exponent(self, 1.0);
         }
 
         pub fn log(&self, base: f64) -> Self {
@@ -583,10 +611,30 @@ mod ops {
         }
 
         pub fn abs_log(&self, base: f64) -> Self {
-            return BigNum {
-                is_negative: false,
-                exponent: self.exponent.log(base)
-            };
+            // There's no logarithm in base 1, as 1^y is 1 for every y
+            if base == 1.0 {
+                return BigNum::NAN;
+            }
+
+            return scaled_exponent(self, 1.0 / base.log10());
+        }
+
+        /// Logarithm in a base that may not fit in an f64, like e1e300.
+        pub fn log_b(&self, base: BigNum) -> Self {
+            if self.is_negative || base.is_negative {
+                return BigNum::NAN;
+            }
+
+            return self.abs_log_b(base);
+        }
+
+        pub fn abs_log_b(&self, base: BigNum) -> Self {
+            if base.exponent == 0.0 {
+                return BigNum::NAN;
+            }
+
+            // log_b(x) = log10(x) / log10(b), and both logarithms are exponents
+            return BigNum::from_f64(self.exponent) / BigNum::from_f64(base.exponent);
         }
 
         pub fn log2(&self) -> Self {
@@ -598,10 +646,7 @@ mod ops {
         }
 
         pub fn abs_log2(&self) -> Self {
-            return BigNum {
-                is_negative: false,
-                exponent: self.exponent.log2()
-            };
+            return scaled_exponent(self, std::f64::consts::LOG2_10);
         }
 
         pub fn ln(&self) -> Self {
@@ -613,10 +658,7 @@ mod ops {
         }
 
         pub fn abs_ln(&self) -> Self {
-            return BigNum {
-                is_negative: false,
-                exponent: self.exponent.ln()
-            };
+            return scaled_exponent(self, std::f64::consts::LN_10);
         }
 
         pub fn hypot(&self, other: &Self) -> Self {
@@ -624,6 +666,54 @@ mod ops {
         }
     }
 
+    // base^x, given log10(base), which is just x log10(base) in the exponent
+    fn exp_with_base(x: &BigNum, log10_base: f64) -> BigNum {
+        if x.is_nan() {
+            return BigNum::NAN;
+        }
+
+        let exponent = match x.to_f64() {
+            Some(value) if value.is_finite() => value * log10_base,
+            // x doesn't fit in an f64, but x log10(base) might
+            _ => {
+                let magnitude = 10_f64.powf(x.exponent + log10_base.log10());
+                if x.is_negative { -magnitude } else { magnitude }
+            }
+        };
+
+        return BigNum {
+            is_negative: false,
+            exponent
+        };
+    }
+
+    // f(x) for functions where f(x) / x is close to 1 for |x| < 1, so the exponent of x carries over exactly
+    fn with_ratio(x: &BigNum, f: fn(f64) -> f64) -> BigNum {
+        let value = x.to_f64().unwrap_or(f64::NAN);
+
+        // Arguments too small for an f64 have f(x) = x
+        let ratio = if value == 0.0 { 1.0 } else { f(value) / value };
+
+        return BigNum {
+            is_negative: x.is_negative,
+            exponent: x.exponent + ratio.log10()
+        };
+    }
+
+    // The exponent times the factor, going through logarithms if that overflows an f64
+    fn scaled_exponent(value: &BigNum, factor: f64) -> BigNum {
+        let scaled = value.exponent * factor;
+
+        if scaled.is_finite() || value.exponent.is_infinite() {
+            return BigNum::from_f64(scaled);
+        }
+
+        return BigNum {
+            is_negative: (value.exponent < 0.0) != (factor < 0.0),
+            exponent: value.exponent.abs().log10() + factor.abs().log10()
+        };
+    }
+
     // Trig functions
     impl BigNum {
         pub fn sin(&self) -> f64 {
@@ -821,4 +911,106 @@ mod ops {
             return self.order(other).1;
         }
     }
+
+    // Iterator folds
+    impl std::iter::Sum for BigNum {
+        fn sum<I: Iterator<Item = BigNum>>(iter: I) -> Self {
+            return sum_all(&iter.collect::<Vec<BigNum>>());
+        }
+    }
+
+    impl<'a> std::iter::Sum<&'a BigNum> for BigNum {
+        fn sum<I: Iterator<Item = &'a BigNum>>(iter: I) -> Self {
+            return sum_all(&iter.copied().collect::<Vec<BigNum>>());
+        }
+    }
+
+    impl std::iter::Product for BigNum {
+        fn product<I: Iterator<Item = BigNum>>(iter: I) -> Self {
+            return product_all(iter);
+        }
+    }
+
+    impl<'a> std::iter::Product<&'a BigNum> for BigNum {
+        fn product<I: Iterator<Item = &'a BigNum>>(iter: I) -> Self {
+            return product_all(iter.copied());
+        }
+    }
+
+    // Neumaier's compensated summation, which keeps track of the rounding error of every addition
+    fn add_compensated(sum: &mut f64, compensation: &mut f64, term: f64) {
+        let total = *sum + term;
+
+        if sum.abs() >= term.abs() {
+            *compensation += (*sum - total) + term;
+        } else {
+            *compensation += (term - total) + *sum;
+        }
+
+        *sum = total;
+    }
+
+    // Adds all terms at once, scaled down by the largest one (log-sum-exp),
+    // so terms too small to change the total one at a time still add up, whatever their order
+    fn sum_all(values: &[BigNum]) -> BigNum {
+        if values.iter().any(|value| value.is_nan()) {
+            return BigNum::NAN;
+        }
+
+        let has_infinity = values.iter().any(|value| value.is_infinite() && !value.is_negative);
+        let has_negative_infinity = values.iter().any(|value| value.is_infinite() && value.is_negative);
+
+        // Infinity - Infinity = NaN
+        match (has_infinity, has_negative_infinity) {
+            (true, true) => return BigNum::NAN,
+            (true, false) => return BigNum::INFINITY,
+            (false, true) => return BigNum::NEG_INFINITY,
+            (false, false) => {}
+        }
+
+        let max_exponent = values.iter().map(|value| value.exponent).fold(f64::NEG_INFINITY, f64::max);
+
+        // Every term is zero
+        if max_exponent == f64::NEG_INFINITY {
+            return BigNum::ZERO;
+        }
+
+        let mut sum = 0.0;
+        let mut compensation = 0.0;
+
+        for value in values {
+            let term = 10_f64.powf(value.exponent - max_exponent);
+            add_compensated(&mut sum, &mut compensation, if value.is_negative { -term } else { term });
+        }
+
+        let total = sum + compensation;
+
+        if total == 0.0 {
+            return BigNum::ZERO;
+        }
+
+        // log10(sum(10^e)) = max + log10(sum(10^(e - max)))
+        return BigNum {
+            is_negative: total < 0.0,
+            exponent: max_exponent + total.abs().log10()
+        };
+    }
+
+    // Multiplying adds the exponents, which is done with compensation as well
+    fn product_all(values: impl Iterator<Item = BigNum>) -> BigNum {
+        let mut is_negative = false;
+        let mut exponent = 0.0;
+        let mut compensation = 0.0;
+
+        for value in values {
+            is_negative ^= value.is_negative;
+            add_compensated(&mut exponent, &mut compensation, value.exponent);
+        }
+
+        // The compensation is meaningless once a factor is 0 or Infinity (0 * Infinity = NaN, as -Infinity + Infinity is NaN)
+        return BigNum {
+            is_negative,
+            exponent: if exponent.is_finite() { exponent + compensation } else { exponent }
+        };
+    }
 }
\ No newline at end of file
@@ -0,0 +1,842 @@
+mod pack {
+    use std::{fmt, ops};
+    use std::cmp::Ordering;
+    use std::num::FpCategory;
+    use std::str::FromStr;
+
+    use crate::bignum::{BigNum, BigNumError, BinaryDecodeError, JsonError, PackedBigNum, ParseBigNumError};
+
+    const SIGN_BIT: u64 = 1;
+
+    // Every NaN is stored as this, so a NaN payload can't be mistaken for a sign
+    const CANONICAL_NAN: u64 = 0x7FF8_0000_0000_0000;
+
+    impl PackedBigNum {
+        pub const NEG_INFINITY: PackedBigNum = PackedBigNum::from_bignum(BigNum::NEG_INFINITY);
+        pub const MIN: PackedBigNum = PackedBigNum::from_bignum(BigNum::MIN);
+        pub const NEG_ONE: PackedBigNum = PackedBigNum::from_bignum(BigNum::NEG_ONE);
+        pub const MAX_NEGATIVE: PackedBigNum = PackedBigNum::from_bignum(BigNum::MAX_NEGATIVE);
+        pub const ZERO: PackedBigNum = PackedBigNum::from_bignum(BigNum::ZERO);
+        pub const MIN_POSITIVE: PackedBigNum = PackedBigNum::from_bignum(BigNum::MIN_POSITIVE);
+        pub const ONE: PackedBigNum = PackedBigNum::from_bignum(BigNum::ONE);
+        pub const MAX: PackedBigNum = PackedBigNum::from_bignum(BigNum::MAX);
+        pub const INFINITY: PackedBigNum = PackedBigNum::from_bignum(BigNum::INFINITY);
+        pub const NAN: PackedBigNum = PackedBigNum(CANONICAL_NAN);
+        pub const PI: PackedBigNum = PackedBigNum::from_bignum(BigNum::PI);
+        pub const TAU: PackedBigNum = PackedBigNum::from_bignum(BigNum::TAU);
+        pub const E: PackedBigNum = PackedBigNum::from_bignum(BigNum::E);
+
+        /// Packs a BigNum, giving up the lowest bit of the exponent's mantissa to hold the sign.
+        /// The exponent is rounded towards zero by at most one unit in the last place.
+        pub const fn from_bignum(value: BigNum) -> Self {
+            if value.exponent.is_nan() {
+                return PackedBigNum(CANONICAL_NAN);
+            }
+
+            let sign = if value.is_negative { SIGN_BIT } else { 0 };
+
+            return PackedBigNum((value.exponent.to_bits() & !SIGN_BIT) | sign);
+        }
+
+        pub const fn to_bignum(self) -> BigNum {
+            if self.0 == CANONICAL_NAN {
+                return BigNum::NAN;
+            }
+
+            return BigNum {
+                is_negative: self.0 & SIGN_BIT != 0,
+                exponent: f64::from_bits(self.0 & !SIGN_BIT)
+            };
+        }
+
+        pub const fn from_bits(bits: u64) -> Self {
+            return PackedBigNum(bits);
+        }
+
+        pub const fn to_bits(self) -> u64 {
+            return self.0;
+        }
+
+        pub const fn is_negative(self) -> bool {
+            return self.0 != CANONICAL_NAN && self.0 & SIGN_BIT != 0;
+        }
+
+        pub const fn exponent(self) -> f64 {
+            return self.to_bignum().exponent;
+        }
+
+        // Functions are done on the unpacked BigNum, like arithmetic
+        fn map(self, operation: impl Fn(&BigNum) -> BigNum) -> Self {
+            return PackedBigNum::from_bignum(operation(&self.to_bignum()));
+        }
+
+        fn unpack_all(values: &[PackedBigNum]) -> Vec<BigNum> {
+            return values.iter().map(|value| value.to_bignum()).collect();
+        }
+
+        fn pack_all(values: Vec<BigNum>) -> Vec<PackedBigNum> {
+            return values.into_iter().map(PackedBigNum::from_bignum).collect();
+        }
+    }
+
+    // Conversions
+    impl PackedBigNum {
+        pub fn from_exponent(exponent: f64, is_negative: bool) -> Self {
+            return PackedBigNum::from_bignum(BigNum::from_exponent(exponent, is_negative));
+        }
+
+        pub fn from_f64(value: f64) -> Self {
+            return PackedBigNum::from_bignum(BigNum::from_f64(value));
+        }
+
+        pub fn from_f32(value: f32) -> Self {
+            return PackedBigNum::from_bignum(BigNum::from_f32(value));
+        }
+
+        pub fn from_string(value: &str) -> Result<Self, ParseBigNumError> {
+            return BigNum::from_string(value).map(PackedBigNum::from_bignum);
+        }
+
+        pub fn from_string_suffix(value: &str) -> Result<Self, ParseBigNumError> {
+            return BigNum::from_string_suffix(value).map(PackedBigNum::from_bignum);
+        }
+
+        pub fn from_string_suffix_long(value: &str) -> Result<Self, ParseBigNumError> {
+            return BigNum::from_string_suffix_long(value).map(PackedBigNum::from_bignum);
+        }
+
+        pub fn to_f64(self) -> Option<f64> {
+            return self.to_bignum().to_f64();
+        }
+
+        pub fn to_string_num(self, precision: usize) -> Option<String> {
+            return self.to_bignum().to_string_num(precision);
+        }
+
+        pub fn to_string_sci(self, precision: usize) -> String {
+            return self.to_bignum().to_string_sci(precision);
+        }
+
+        pub fn to_string_exact(self) -> String {
+            return self.to_bignum().to_string_exact();
+        }
+
+        pub fn to_string_eng(self, precision: usize) -> String {
+            return self.to_bignum().to_string_eng(precision);
+        }
+
+        pub fn to_string_si(self, precision: usize, unit: &str) -> String {
+            return self.to_bignum().to_string_si(precision, unit);
+        }
+
+        pub fn to_string_log(self, precision: usize) -> String {
+            return self.to_bignum().to_string_log(precision);
+        }
+
+        pub fn to_string_suffix(self, precision: usize) -> String {
+            return self.to_bignum().to_string_suffix(precision);
+        }
+
+        pub fn to_string_suffix_long(self, precision: usize) -> String {
+            return self.to_bignum().to_string_suffix_long(precision);
+        }
+    }
+
+    // Encodings, in the same formats as BigNum's
+    impl PackedBigNum {
+        pub fn to_json(self) -> String {
+            return self.to_bignum().to_json();
+        }
+
+        pub fn from_json(text: &str) -> Result<Self, JsonError> {
+            return BigNum::from_json(text).map(PackedBigNum::from_bignum);
+        }
+
+        pub fn to_json_array(values: &[PackedBigNum]) -> String {
+            return BigNum::to_json_array(&PackedBigNum::unpack_all(values));
+        }
+
+        pub fn from_json_array(text: &str) -> Result<Vec<Self>, JsonError> {
+            return BigNum::from_json_array(text).map(PackedBigNum::pack_all);
+        }
+
+        pub fn to_json_object<'a>(entries: impl IntoIterator<Item = (&'a str, &'a PackedBigNum)>) -> String {
+            let entries: Vec<(&str, BigNum)> = entries.into_iter().map(|(key, value)| (key, value.to_bignum())).collect();
+
+            return BigNum::to_json_object(entries.iter().map(|(key, value)| (*key, value)));
+        }
+
+        pub fn from_json_object(text: &str) -> Result<Vec<(String, Self)>, JsonError> {
+            let entries = BigNum::from_json_object(text)?;
+
+            return Ok(entries.into_iter().map(|(key, value)| (key, PackedBigNum::from_bignum(value))).collect());
+        }
+
+        pub fn to_le_bytes(self) -> [u8; 9] {
+            return self.to_bignum().to_le_bytes();
+        }
+
+        pub fn to_be_bytes(self) -> [u8; 9] {
+            return self.to_bignum().to_be_bytes();
+        }
+
+        pub fn from_le_bytes(bytes: [u8; 9]) -> Result<Self, BinaryDecodeError> {
+            return BigNum::from_le_bytes(bytes).map(PackedBigNum::from_bignum);
+        }
+
+        pub fn from_be_bytes(bytes: [u8; 9]) -> Result<Self, BinaryDecodeError> {
+            return BigNum::from_be_bytes(bytes).map(PackedBigNum::from_bignum);
+        }
+
+        pub fn encode_slice(values: &[PackedBigNum]) -> Vec<u8> {
+            return BigNum::encode_slice(&PackedBigNum::unpack_all(values));
+        }
+
+        pub fn decode_slice(bytes: &[u8]) -> Result<Vec<Self>, BinaryDecodeError> {
+            return BigNum::decode_slice(bytes).map(PackedBigNum::pack_all);
+        }
+
+        pub fn to_sortable_bytes(self) -> [u8; 9] {
+            return self.to_bignum().to_sortable_bytes();
+        }
+
+        pub fn from_sortable_bytes(bytes: [u8; 9]) -> Option<Self> {
+            return BigNum::from_sortable_bytes(bytes).map(PackedBigNum::from_bignum);
+        }
+
+        pub fn to_sortable_u64(self) -> u64 {
+            return self.to_bignum().to_sortable_u64();
+        }
+
+        pub fn from_sortable_u64(value: u64) -> Option<Self> {
+            return BigNum::from_sortable_u64(value).map(PackedBigNum::from_bignum);
+        }
+    }
+
+    // Classification
+    impl PackedBigNum {
+        pub fn is_nan(self) -> bool {
+            return self.to_bignum().is_nan();
+        }
+
+        pub fn is_infinite(self) -> bool {
+            return self.to_bignum().is_infinite();
+        }
+
+        pub fn is_finite(self) -> bool {
+            return self.to_bignum().is_finite();
+        }
+
+        pub fn is_sign_positive(self) -> bool {
+            return self.to_bignum().is_sign_positive();
+        }
+
+        pub fn is_sign_negative(self) -> bool {
+            return self.to_bignum().is_sign_negative();
+        }
+
+        pub fn is_zero(self) -> bool {
+            return self.to_bignum().is_zero();
+        }
+
+        pub fn classify(self) -> FpCategory {
+            return self.to_bignum().classify();
+        }
+    }
+
+    // Exponentiation and logarithms
+    impl PackedBigNum {
+        pub fn recip(self) -> Self {
+            return self.map(BigNum::recip);
+        }
+
+        pub fn powf(self, exp: f64) -> Self {
+            return self.map(|value| value.powf(exp));
+        }
+
+        pub fn powi(self, exp: i32) -> Self {
+            return self.map(|value| value.powi(exp));
+        }
+
+        pub fn powb(self, exp: PackedBigNum) -> Self {
+            return self.map(|value| value.powb(exp.to_bignum()));
+        }
+
+        pub fn sqr(self) -> Self {
+            return self.map(BigNum::sqr);
+        }
+
+        pub fn cube(self) -> Self {
+            return self.map(BigNum::cube);
+        }
+
+        pub fn sqrt(self) -> Self {
+            return self.map(BigNum::sqrt);
+        }
+
+        pub fn cbrt(self) -> Self {
+            return self.map(BigNum::cbrt);
+        }
+
+        pub fn exp(self) -> Self {
+            return self.map(BigNum::exp);
+        }
+
+        pub fn exp2(self) -> Self {
+            return self.map(BigNum::exp2);
+        }
+
+        pub fn exp10(self) -> Self {
+            return self.map(BigNum::exp10);
+        }
+
+        pub fn exp_m1(self) -> Self {
+            return self.map(BigNum::exp_m1);
+        }
+
+        pub fn ln_1p(self) -> Self {
+            return self.map(BigNum::ln_1p);
+        }
+
+        pub fn log10(self) -> Self {
+            return self.map(BigNum::log10);
+        }
+
+        pub fn abs_log10(self) -> Self {
+            return self.map(BigNum::abs_log10);
+        }
+
+        pub fn log(self, base: f64) -> Self {
+            return self.map(|value| value.log(base));
+        }
+
+        pub fn abs_log(self, base: f64) -> Self {
+            return self.map(|value| value.abs_log(base));
+        }
+
+        pub fn log_b(self, base: PackedBigNum) -> Self {
+            return self.map(|value| value.log_b(base.to_bignum()));
+        }
+
+        pub fn abs_log_b(self, base: PackedBigNum) -> Self {
+            return self.map(|value| value.abs_log_b(base.to_bignum()));
+        }
+
+        pub fn log2(self) -> Self {
+            return self.map(BigNum::log2);
+        }
+
+        pub fn abs_log2(self) -> Self {
+            return self.map(BigNum::abs_log2);
+        }
+
+        pub fn ln(self) -> Self {
+            return self.map(BigNum::ln);
+        }
+
+        pub fn abs_ln(self) -> Self {
+            return self.map(BigNum::abs_ln);
+        }
+
+        pub fn hypot(self, other: PackedBigNum) -> Self {
+            return self.map(|value| value.hypot(&other.to_bignum()));
+        }
+
+        pub fn tetrate(self, height: f64) -> Self {
+            return self.map(|value| value.tetrate(height));
+        }
+
+        pub fn slog(self, base: f64) -> f64 {
+            return self.to_bignum().slog(base);
+        }
+
+        pub fn ssqrt(self) -> Self {
+            return self.map(BigNum::ssqrt);
+        }
+    }
+
+    // Trigonometry, which only makes sense for numbers that fit in an f64
+    impl PackedBigNum {
+        pub fn sin(self) -> f64 {
+            return self.to_bignum().sin();
+        }
+
+        pub fn cos(self) -> f64 {
+            return self.to_bignum().cos();
+        }
+
+        pub fn sin_cos(self) -> (f64, f64) {
+            return self.to_bignum().sin_cos();
+        }
+
+        pub fn tan(self) -> f64 {
+            return self.to_bignum().tan();
+        }
+
+        pub fn asin(self) -> f64 {
+            return self.to_bignum().asin();
+        }
+
+        pub fn acos(self) -> f64 {
+            return self.to_bignum().acos();
+        }
+
+        pub fn atan(self) -> f64 {
+            return self.to_bignum().atan();
+        }
+
+        pub fn atan2(self, other: PackedBigNum) -> f64 {
+            return self.to_bignum().atan2(&other.to_bignum());
+        }
+
+        pub fn sinh(self) -> f64 {
+            return self.to_bignum().sinh();
+        }
+
+        pub fn cosh(self) -> f64 {
+            return self.to_bignum().cosh();
+        }
+
+        pub fn tanh(self) -> f64 {
+            return self.to_bignum().tanh();
+        }
+
+        pub fn asinh(self) -> f64 {
+            return self.to_bignum().asinh();
+        }
+
+        pub fn acosh(self) -> f64 {
+            return self.to_bignum().acosh();
+        }
+
+        pub fn atanh(self) -> f64 {
+            return self.to_bignum().atanh();
+        }
+
+        pub fn sinc(self) -> f64 {
+            return self.to_bignum().sinc();
+        }
+
+        pub fn cosc(self) -> f64 {
+            return self.to_bignum().cosc();
+        }
+
+        pub fn tanc(self) -> f64 {
+            return self.to_bignum().tanc();
+        }
+    }
+
+    // Special functions
+    impl PackedBigNum {
+        pub fn gamma(self) -> Self {
+            return self.map(BigNum::gamma);
+        }
+
+        pub fn ln_gamma(self) -> Self {
+            return self.map(BigNum::ln_gamma);
+        }
+
+        pub fn factorial(self) -> Self {
+            return self.map(BigNum::factorial);
+        }
+
+        pub fn lambert_w0(self) -> Self {
+            return self.map(BigNum::lambert_w0);
+        }
+
+        pub fn lambert_wm1(self) -> Self {
+            return self.map(BigNum::lambert_wm1);
+        }
+
+        pub fn binomial(n: PackedBigNum, k: PackedBigNum) -> Result<Self, BigNumError> {
+            return BigNum::binomial(n.to_bignum(), k.to_bignum()).map(PackedBigNum::from_bignum);
+        }
+
+        pub fn permutations(n: PackedBigNum, k: PackedBigNum) -> Result<Self, BigNumError> {
+            return BigNum::permutations(n.to_bignum(), k.to_bignum()).map(PackedBigNum::from_bignum);
+        }
+
+        pub fn multinomial(ks: &[PackedBigNum]) -> Result<Self, BigNumError> {
+            return BigNum::multinomial(&PackedBigNum::unpack_all(ks)).map(PackedBigNum::from_bignum);
+        }
+
+        pub fn catalan(n: PackedBigNum) -> Result<Self, BigNumError> {
+            return BigNum::catalan(n.to_bignum()).map(PackedBigNum::from_bignum);
+        }
+    }
+
+    // Checked operations
+    impl PackedBigNum {
+        pub fn checked_add(self, other: PackedBigNum) -> Result<Self, BigNumError> {
+            return self.to_bignum().checked_add(&other.to_bignum()).map(PackedBigNum::from_bignum);
+        }
+
+        pub fn checked_sub(self, other: PackedBigNum) -> Result<Self, BigNumError> {
+            return self.to_bignum().checked_sub(&other.to_bignum()).map(PackedBigNum::from_bignum);
+        }
+
+        pub fn checked_mul(self, other: PackedBigNum) -> Result<Self, BigNumError> {
+            return self.to_bignum().checked_mul(&other.to_bignum()).map(PackedBigNum::from_bignum);
+        }
+
+        pub fn checked_div(self, other: PackedBigNum) -> Result<Self, BigNumError> {
+            return self.to_bignum().checked_div(&other.to_bignum()).map(PackedBigNum::from_bignum);
+        }
+
+        pub fn checked_recip(self) -> Result<Self, BigNumError> {
+            return self.to_bignum().checked_recip().map(PackedBigNum::from_bignum);
+        }
+
+        pub fn checked_powf(self, exp: f64) -> Result<Self, BigNumError> {
+            return self.to_bignum().checked_powf(exp).map(PackedBigNum::from_bignum);
+        }
+
+        pub fn checked_powb(self, exp: PackedBigNum) -> Result<Self, BigNumError> {
+            return self.to_bignum().checked_powb(&exp.to_bignum()).map(PackedBigNum::from_bignum);
+        }
+
+        pub fn checked_sqrt(self) -> Result<Self, BigNumError> {
+            return self.to_bignum().checked_sqrt().map(PackedBigNum::from_bignum);
+        }
+
+        pub fn checked_log10(self) -> Result<Self, BigNumError> {
+            return self.to_bignum().checked_log10().map(PackedBigNum::from_bignum);
+        }
+
+        pub fn checked_ln(self) -> Result<Self, BigNumError> {
+            return self.to_bignum().checked_ln().map(PackedBigNum::from_bignum);
+        }
+    }
+
+    // Rounding, sign and comparisons
+    impl PackedBigNum {
+        pub fn floor(self) -> Self {
+            return self.map(BigNum::floor);
+        }
+
+        pub fn ceil(self) -> Self {
+            return self.map(BigNum::ceil);
+        }
+
+        pub fn round(self) -> Self {
+            return self.map(BigNum::round);
+        }
+
+        pub fn trunc(self) -> Self {
+            return self.map(BigNum::trunc);
+        }
+
+        pub fn fract(self) -> Self {
+            return self.map(BigNum::fract);
+        }
+
+        pub fn almost_equal(self, other: PackedBigNum, tolerance: PackedBigNum) -> bool {
+            return self.to_bignum().almost_equal(&other.to_bignum(), &tolerance.to_bignum());
+        }
+
+        pub fn abs(self) -> Self {
+            return self.map(BigNum::abs);
+        }
+
+        pub fn signum(self) -> Self {
+            return self.map(BigNum::signum);
+        }
+
+        pub fn clamp(self, min: PackedBigNum, max: PackedBigNum) -> Option<Self> {
+            return self.to_bignum().clamp(&min.to_bignum(), &max.to_bignum()).map(PackedBigNum::from_bignum);
+        }
+
+        pub fn order(self, other: PackedBigNum) -> (Self, Self) {
+            let (low, high) = self.to_bignum().order(&other.to_bignum());
+
+            return (PackedBigNum::from_bignum(low), PackedBigNum::from_bignum(high));
+        }
+
+        pub fn min(self, other: PackedBigNum) -> Self {
+            return self.map(|value| value.min(&other.to_bignum()));
+        }
+
+        pub fn max(self, other: PackedBigNum) -> Self {
+            return self.map(|value| value.max(&other.to_bignum()));
+        }
+    }
+
+    impl From<BigNum> for PackedBigNum {
+        fn from(value: BigNum) -> Self {
+            return PackedBigNum::from_bignum(value);
+        }
+    }
+
+    impl From<PackedBigNum> for BigNum {
+        fn from(value: PackedBigNum) -> Self {
+            return value.to_bignum();
+        }
+    }
+
+    impl From<f64> for PackedBigNum {
+        fn from(value: f64) -> Self {
+            return PackedBigNum::from_bignum(BigNum::from_f64(value));
+        }
+    }
+
+    impl FromStr for PackedBigNum {
+        type Err = ParseBigNumError;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            return PackedBigNum::from_string(value);
+        }
+    }
+
+    // Compared as BigNums, so NaN isn't equal to itself
+    impl PartialEq for PackedBigNum {
+        fn eq(&self, other: &Self) -> bool {
+            return self.to_bignum() == other.to_bignum();
+        }
+    }
+
+    impl PartialOrd for PackedBigNum {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            return self.to_bignum().partial_cmp(&other.to_bignum());
+        }
+    }
+
+    impl ops::Neg for PackedBigNum {
+        type Output = Self;
+
+        fn neg(self) -> Self::Output {
+            if self.0 == CANONICAL_NAN {
+                return self;
+            }
+
+            return PackedBigNum(self.0 ^ SIGN_BIT);
+        }
+    }
+
+    // Arithmetic is done on the unpacked BigNums
+    impl ops::Add for PackedBigNum {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            return PackedBigNum::from_bignum(self.to_bignum() + rhs.to_bignum());
+        }
+    }
+    impl ops::Add<f64> for PackedBigNum {
+        type Output = Self;
+
+        fn add(self, rhs: f64) -> Self::Output {
+            return PackedBigNum::from_bignum(self.to_bignum() + rhs);
+        }
+    }
+    impl ops::Add<PackedBigNum> for f64 {
+        type Output = PackedBigNum;
+
+        fn add(self, rhs: PackedBigNum) -> Self::Output {
+            return PackedBigNum::from_bignum(self + rhs.to_bignum());
+        }
+    }
+
+    impl ops::AddAssign for PackedBigNum {
+        fn add_assign(&mut self, rhs: Self) {
+            *self = *self + rhs;
+        }
+    }
+    impl ops::AddAssign<f64> for PackedBigNum {
+        fn add_assign(&mut self, rhs: f64) {
+            *self = *self + rhs;
+        }
+    }
+    impl ops::AddAssign<PackedBigNum> for f64 {
+        fn add_assign(&mut self, rhs: PackedBigNum) {
+            *self += rhs.to_bignum();
+        }
+    }
+
+    impl ops::Sub for PackedBigNum {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self::Output {
+            return PackedBigNum::from_bignum(self.to_bignum() - rhs.to_bignum());
+        }
+    }
+    impl ops::Sub<f64> for PackedBigNum {
+        type Output = Self;
+
+        fn sub(self, rhs: f64) -> Self::Output {
+            return PackedBigNum::from_bignum(self.to_bignum() - rhs);
+        }
+    }
+    impl ops::Sub<PackedBigNum> for f64 {
+        type Output = PackedBigNum;
+
+        fn sub(self, rhs: PackedBigNum) -> Self::Output {
+            return PackedBigNum::from_bignum(self - rhs.to_bignum());
+        }
+    }
+
+    impl ops::SubAssign for PackedBigNum {
+        fn sub_assign(&mut self, rhs: Self) {
+            *self = *self - rhs;
+        }
+    }
+    impl ops::SubAssign<f64> for PackedBigNum {
+        fn sub_assign(&mut self, rhs: f64) {
+            *self = *self - rhs;
+        }
+    }
+    impl ops::SubAssign<PackedBigNum> for f64 {
+        fn sub_assign(&mut self, rhs: PackedBigNum) {
+            *self -= rhs.to_bignum();
+        }
+    }
+
+    impl ops::Mul for PackedBigNum {
+        type Output = Self;
+
+        fn mul(self, rhs: Self) -> Self::Output {
+            return PackedBigNum::from_bignum(self.to_bignum() * rhs.to_bignum());
+        }
+    }
+    impl ops::Mul<f64> for PackedBigNum {
+        type Output = Self;
+
+        fn mul(self, rhs: f64) -> Self::Output {
+            return PackedBigNum::from_bignum(self.to_bignum() * rhs);
+        }
+    }
+    impl ops::Mul<PackedBigNum> for f64 {
+        type Output = PackedBigNum;
+
+        fn mul(self, rhs: PackedBigNum) -> Self::Output {
+            return PackedBigNum::from_bignum(self * rhs.to_bignum());
+        }
+    }
+
+    impl ops::MulAssign for PackedBigNum {
+        fn mul_assign(&mut self, rhs: Self) {
+            *self = *self * rhs;
+        }
+    }
+    impl ops::MulAssign<f64> for PackedBigNum {
+        fn mul_assign(&mut self, rhs: f64) {
+            *self = *self * rhs;
+        }
+    }
+    impl ops::MulAssign<PackedBigNum> for f64 {
+        fn mul_assign(&mut self, rhs: PackedBigNum) {
+            *self *= rhs.to_bignum();
+        }
+    }
+
+    impl ops::Div for PackedBigNum {
+        type Output = Self;
+
+        fn div(self, rhs: Self) -> Self::Output {
+            return PackedBigNum::from_bignum(self.to_bignum() / rhs.to_bignum());
+        }
+    }
+    impl ops::Div<f64> for PackedBigNum {
+        type Output = Self;
+
+        fn div(self, rhs: f64) -> Self::Output {
+            return PackedBigNum::from_bignum(self.to_bignum() / rhs);
+        }
+    }
+    impl ops::Div<PackedBigNum> for f64 {
+        type Output = PackedBigNum;
+
+        fn div(self, rhs: PackedBigNum) -> Self::Output {
+            return PackedBigNum::from_bignum(self / rhs.to_bignum());
+        }
+    }
+
+    impl ops::DivAssign for PackedBigNum {
+        fn div_assign(&mut self, rhs: Self) {
+            *self = *self / rhs;
+        }
+    }
+    impl ops::DivAssign<f64> for PackedBigNum {
+        fn div_assign(&mut self, rhs: f64) {
+            *self = *self / rhs;
+        }
+    }
+    impl ops::DivAssign<PackedBigNum> for f64 {
+        fn div_assign(&mut self, rhs: PackedBigNum) {
+            *self /= rhs.to_bignum();
+        }
+    }
+
+    impl ops::Rem for PackedBigNum {
+        type Output = Self;
+
+        fn rem(self, rhs: Self) -> Self::Output {
+            return PackedBigNum::from_bignum(self.to_bignum() % rhs.to_bignum());
+        }
+    }
+    impl ops::Rem<f64> for PackedBigNum {
+        type Output = Self;
+
+        fn rem(self, rhs: f64) -> Self::Output {
+            return PackedBigNum::from_bignum(self.to_bignum() % rhs);
+        }
+    }
+    impl ops::Rem<PackedBigNum> for f64 {
+        type Output = PackedBigNum;
+
+        fn rem(self, rhs: PackedBigNum) -> Self::Output {
+            return PackedBigNum::from_bignum(self % rhs.to_bignum());
+        }
+    }
+
+    impl ops::RemAssign for PackedBigNum {
+        fn rem_assign(&mut self, rhs: Self) {
+            *self = *self % rhs;
+        }
+    }
+    impl ops::RemAssign<f64> for PackedBigNum {
+        fn rem_assign(&mut self, rhs: f64) {
+            *self = *self % rhs;
+        }
+    }
+    impl ops::RemAssign<PackedBigNum> for f64 {
+        fn rem_assign(&mut self, rhs: PackedBigNum) {
+            *self %= rhs.to_bignum();
+        }
+    }
+
+    // Summed as BigNums, so they keep the compensated accumulation
+    impl std::iter::Sum for PackedBigNum {
+        fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+            return PackedBigNum::from_bignum(iter.map(PackedBigNum::to_bignum).sum());
+        }
+    }
+
+    impl<'a> std::iter::Sum<&'a PackedBigNum> for PackedBigNum {
+        fn sum<I: Iterator<Item = &'a PackedBigNum>>(iter: I) -> Self {
+            return iter.copied().sum();
+        }
+    }
+
+    impl std::iter::Product for PackedBigNum {
+        fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+            return PackedBigNum::from_bignum(iter.map(PackedBigNum::to_bignum).product());
+        }
+    }
+
+    impl<'a> std::iter::Product<&'a PackedBigNum> for PackedBigNum {
+        fn product<I: Iterator<Item = &'a PackedBigNum>>(iter: I) -> Self {
+            return iter.copied().product();
+        }
+    }
+
+    impl fmt::Display for PackedBigNum {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            return fmt::Display::fmt(&self.to_bignum(), f);
+        }
+    }
+
+    impl fmt::Debug for PackedBigNum {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            return fmt::Debug::fmt(&self.to_bignum(), f);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,210 @@
+mod save {
+    use crate::bignum::{BigNum, SaveDecodeError, SaveValue};
+
+    // Layout, before base64: version byte, tagged values, then a CRC-32 of everything before it (big-endian)
+    const TAG_FALSE: u8 = 0;
+    const TAG_TRUE: u8 = 1;
+    const TAG_INTEGER: u8 = 2;
+    const TAG_POSITIVE: u8 = 3;
+    const TAG_NEGATIVE: u8 = 4;
+
+    const CHECKSUM_LENGTH: usize = 4;
+
+    // URL-safe base64, without padding
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+    impl From<BigNum> for SaveValue {
+        fn from(value: BigNum) -> Self {
+            return SaveValue::Number(value);
+        }
+    }
+
+    impl From<i64> for SaveValue {
+        fn from(value: i64) -> Self {
+            return SaveValue::Integer(value);
+        }
+    }
+
+    impl From<bool> for SaveValue {
+        fn from(value: bool) -> Self {
+            return SaveValue::Boolean(value);
+        }
+    }
+
+    impl SaveValue {
+        /// Version of the format written by `encode`, bumped whenever the encoding changes.
+        pub const VERSION: u8 = 1;
+
+        /// Encodes values into a URL-safe string that can be copied and pasted.
+        /// Numbers are stored losslessly, and integers take fewer bytes the closer they are to zero.
+        pub fn encode(values: &[SaveValue]) -> String {
+            let mut bytes = vec![SaveValue::VERSION];
+
+            for value in values {
+                match value {
+                    SaveValue::Boolean(value) => bytes.push(if *value { TAG_TRUE } else { TAG_FALSE }),
+                    SaveValue::Integer(value) => {
+                        bytes.push(TAG_INTEGER);
+                        write_varint(&mut bytes, zigzag_encode(*value));
+                    },
+                    SaveValue::Number(value) => {
+                        bytes.push(if value.is_negative { TAG_NEGATIVE } else { TAG_POSITIVE });
+                        bytes.extend_from_slice(&value.exponent.to_le_bytes());
+                    }
+                }
+            }
+
+            let checksum = crc32(&bytes);
+            bytes.extend_from_slice(&checksum.to_be_bytes());
+
+            return base64_encode(&bytes);
+        }
+
+        /// Decodes a string from `encode`, checking the checksum and version first.
+        pub fn decode(text: &str) -> Result<Vec<SaveValue>, SaveDecodeError> {
+            let bytes = base64_decode(text)?;
+
+            if bytes.len() < 1 + CHECKSUM_LENGTH {
+                return Err(SaveDecodeError::UnexpectedEnd { position: bytes.len() });
+            }
+
+            let (data, checksum) = bytes.split_at(bytes.len() - CHECKSUM_LENGTH);
+            let expected = u32::from_be_bytes(checksum.try_into().unwrap());
+            let found = crc32(data);
+
+            if expected != found {
+                return Err(SaveDecodeError::ChecksumMismatch { expected, found });
+            }
+
+            if data[0] != SaveValue::VERSION {
+                return Err(SaveDecodeError::UnsupportedVersion { version: data[0] });
+            }
+
+            let mut values = Vec::new();
+            let mut position = 1;
+
+            while position < data.len() {
+                let tag = data[position];
+                position += 1;
+
+                let value = match tag {
+                    TAG_FALSE => SaveValue::Boolean(false),
+                    TAG_TRUE => SaveValue::Boolean(true),
+                    TAG_INTEGER => SaveValue::Integer(zigzag_decode(read_varint(data, &mut position)?)),
+                    TAG_POSITIVE | TAG_NEGATIVE => {
+                        let exponent = data.get(position..(position + 8))
+                            .ok_or(SaveDecodeError::UnexpectedEnd { position: data.len() })?;
+                        position += 8;
+
+                        SaveValue::Number(BigNum::from_exponent(
+                            f64::from_le_bytes(exponent.try_into().unwrap()),
+                            tag == TAG_NEGATIVE
+                        ))
+                    },
+                    tag => return Err(SaveDecodeError::UnknownTag { position: position - 1, tag })
+                };
+
+                values.push(value);
+            }
+
+            return Ok(values);
+        }
+    }
+
+    // Maps signed integers to unsigned ones so small negative numbers stay small: 0, -1, 1, -2, ... => 0, 1, 2, 3, ...
+    fn zigzag_encode(value: i64) -> u64 {
+        return ((value << 1) ^ (value >> 63)) as u64;
+    }
+
+    fn zigzag_decode(value: u64) -> i64 {
+        return ((value >> 1) as i64) ^ -((value & 1) as i64);
+    }
+
+    // 7 bits per byte, with the top bit set on every byte but the last
+    fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+        while value >= 0x80 {
+            bytes.push((value as u8 & 0x7F) | 0x80);
+            value >>= 7;
+        }
+
+        bytes.push(value as u8);
+    }
+
+    fn read_varint(bytes: &[u8], position: &mut usize) -> Result<u64, SaveDecodeError> {
+        let start = *position;
+        let mut value: u64 = 0;
+        let mut shift = 0;
+
+        loop {
+            let byte = *bytes.get(*position).ok_or(SaveDecodeError::UnexpectedEnd { position: bytes.len() })?;
+            *position += 1;
+
+            if shift >= 64 || (shift == 63 && byte > 1) {
+                return Err(SaveDecodeError::IntegerOverflow { position: start });
+            }
+
+            value |= ((byte & 0x7F) as u64) << shift;
+            shift += 7;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+    }
+
+    // CRC-32 (IEEE), bit by bit since save strings are short
+    fn crc32(bytes: &[u8]) -> u32 {
+        let mut crc = !0_u32;
+
+        for &byte in bytes {
+            crc ^= byte as u32;
+
+            for _ in 0..8 {
+                crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+            }
+        }
+
+        return !crc;
+    }
+
+    fn base64_encode(bytes: &[u8]) -> String {
+        let mut text = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+        for chunk in bytes.chunks(3) {
+            let group = chunk.iter().enumerate().fold(0_u32, |group, (index, &byte)| group | (byte as u32) << (16 - 8 * index));
+
+            // 2 characters for 1 byte, 3 for 2 bytes and 4 for 3 bytes
+            for index in 0..(chunk.len() + 1) {
+                text.push(ALPHABET[(group >> (18 - 6 * index)) as usize & 0x3F] as char);
+            }
+        }
+
+        return text;
+    }
+
+    fn base64_decode(text: &str) -> Result<Vec<u8>, SaveDecodeError> {
+        // A single character left over can't hold a whole byte
+        if text.len() % 4 == 1 {
+            return Err(SaveDecodeError::InvalidLength);
+        }
+
+        let mut bytes = Vec::with_capacity(text.len() / 4 * 3 + 2);
+
+        for (chunk_index, chunk) in text.as_bytes().chunks(4).enumerate() {
+            let mut group: u32 = 0;
+
+            for (index, &character) in chunk.iter().enumerate() {
+                let digit = ALPHABET.iter().position(|&c| c == character)
+                    .ok_or(SaveDecodeError::InvalidCharacter { position: chunk_index * 4 + index })?;
+
+                group |= (digit as u32) << (18 - 6 * index);
+            }
+
+            for index in 0..(chunk.len() - 1) {
+                bytes.push((group >> (16 - 8 * index)) as u8);
+            }
+        }
+
+        return Ok(bytes);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,109 @@
+mod sort {
+    use crate::bignum::BigNum;
+
+    const NEGATIVE_TAG: u8 = 0x00;
+    const POSITIVE_TAG: u8 = 0x01;
+    const NAN_TAG: u8 = 0xFF;
+
+    const SIGN_MASK: u64 = 1 << 63;
+
+    // Maps an f64 to a u64 with the same order, from -Infinity up to Infinity
+    fn exponent_key(exponent: f64) -> u64 {
+        let bits = exponent.to_bits();
+
+        return if bits & SIGN_MASK != 0 { !bits } else { bits | SIGN_MASK };
+    }
+
+    fn exponent_from_key(key: u64) -> f64 {
+        return f64::from_bits(if key & SIGN_MASK != 0 { key & !SIGN_MASK } else { !key });
+    }
+
+    impl BigNum {
+        /// Encodes the number in 9 bytes whose byte order matches `PartialOrd`, for sorted key-value stores.
+        /// The first byte is 0x00 for negative numbers (including -0) and 0x01 for positive ones,
+        /// followed by the exponent, reversed for negative numbers since bigger exponents are smaller there.
+        /// NaN is encoded as nine 0xFF bytes, after every other number.
+        pub fn to_sortable_bytes(&self) -> [u8; 9] {
+            let mut bytes = [NAN_TAG; 9];
+
+            if self.is_nan() {
+                return bytes;
+            }
+
+            let key = exponent_key(self.exponent);
+
+            let (tag, key) = if self.is_negative {
+                (NEGATIVE_TAG, !key)
+            } else {
+                (POSITIVE_TAG, key)
+            };
+
+            bytes[0] = tag;
+            bytes[1..].copy_from_slice(&key.to_be_bytes());
+
+            return bytes;
+        }
+
+        /// Decodes bytes from `to_sortable_bytes`, or returns None if they aren't a valid encoding.
+        pub fn from_sortable_bytes(bytes: [u8; 9]) -> Option<BigNum> {
+            let key = u64::from_be_bytes(bytes[1..].try_into().unwrap());
+
+            let value = match bytes[0] {
+                NEGATIVE_TAG => BigNum::from_exponent(exponent_from_key(!key), true),
+                POSITIVE_TAG => BigNum::from_exponent(exponent_from_key(key), false),
+                NAN_TAG if key == u64::MAX => return Some(BigNum::NAN),
+                _ => return None
+            };
+
+            // The only NaN is the one with its own tag
+            if value.is_nan() {
+                return None;
+            }
+
+            return Some(value);
+        }
+
+        /// Encodes the number in a u64 whose order matches `PartialOrd`.
+        /// This drops the lowest bit of the exponent to make room for the sign, so numbers
+        /// one unit in the last place apart may get the same key.
+        /// NaN is encoded as u64::MAX, above every other number.
+        pub fn to_sortable_u64(&self) -> u64 {
+            if self.is_nan() {
+                return u64::MAX;
+            }
+
+            let key = exponent_key(self.exponent) >> 1;
+
+            if self.is_negative {
+                return !SIGN_MASK - key;
+            }
+
+            return SIGN_MASK | key;
+        }
+
+        /// Decodes a key from `to_sortable_u64`, or returns None if it isn't a valid encoding.
+        pub fn from_sortable_u64(value: u64) -> Option<BigNum> {
+            if value == u64::MAX {
+                return Some(BigNum::NAN);
+            }
+
+            let (is_negative, half_key) = if value & SIGN_MASK != 0 {
+                (false, value & !SIGN_MASK)
+            } else {
+                (true, !SIGN_MASK - value)
+            };
+
+            // Negative exponents are stored inverted, so the dropped bit comes back as a 1 there.
+            // Either way, the lowest bit of the exponent ends up 0.
+            let key = (half_key << 1) | if half_key & (SIGN_MASK >> 1) == 0 { 1 } else { 0 };
+
+            let result = BigNum::from_exponent(exponent_from_key(key), is_negative);
+
+            if result.is_nan() {
+                return None;
+            }
+
+            return Some(result);
+        }
+    }
+}
\ No newline at end of file
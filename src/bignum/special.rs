@@ -0,0 +1,309 @@
+pub(crate) mod special_functions {
+    use std::f64::consts::{E, LN_10, PI};
+    use crate::bignum::BigNum;
+
+    // ln(2π) / 2
+    pub(crate) const HALF_LN_TAU: f64 = 0.9189385332046728;
+
+    // Stirling's series is shifted up to this point, where its first five terms are accurate to the last bit
+    const STIRLING_MIN: f64 = 15.0;
+
+    // 170! is the largest factorial that fits in an f64
+    const MAX_EXACT_FACTORIAL: f64 = 170.0;
+
+    // Above this, x ln(x) overflows an f64, so ln Γ(x) is only worked out from the exponent
+    const ASYMPTOTIC_EXPONENT: f64 = 300.0;
+
+    // Below this, Γ(x) is 1/x, and the f64 path would round x to 0 or overflow sin(πx)
+    const TINY_EXPONENT: f64 = -15.0;
+
+    // 1/e, where the two real branches of the Lambert W function meet at W = -1
+    const INV_E: f64 = 0.36787944117144233;
+
+    // Below this, W(x) = x - x^2 + ... is x to within the precision of an f64
+    const LAMBERT_LINEAR_EXPONENT: f64 = -20.0;
+
+    // Integers don't survive the round trip through log10 exactly, e.g. 10^log10(3) may be 2.9999999999999996,
+    // which would miss the exact factorials and the poles
+    fn snap_to_integer(x: f64) -> f64 {
+        let rounded = x.round();
+
+        if (x - rounded).abs() <= rounded.abs() * 4.0 * f64::EPSILON {
+            return rounded;
+        }
+
+        return x;
+    }
+
+    impl BigNum {
+        /// Calculates Γ(x). Non-positive integers are poles and give NaN, except ±0 which give ±Infinity.
+        pub fn gamma(&self) -> BigNum {
+            if self.is_nan() || (self.is_infinite() && self.is_negative) {
+                return BigNum::NAN;
+            }
+
+            if self.is_infinite() {
+                return BigNum::INFINITY;
+            }
+
+            if self.is_zero() {
+                return BigNum::from_exponent(f64::INFINITY, self.is_negative);
+            }
+
+            // Γ(x) = 1/x - γ + ..., which is 1/x to within the precision of an f64
+            if self.exponent < TINY_EXPONENT {
+                return BigNum::from_exponent(-self.exponent, self.is_negative);
+            }
+
+            let x = match self.snapped_f64() {
+                Some(x) => x,
+                // Every negative number this large is an integer, and so a pole
+                None => return if self.is_negative { BigNum::NAN } else { BigNum::INFINITY }
+            };
+
+            if x < 0.0 && x.fract() == 0.0 {
+                return BigNum::NAN;
+            }
+
+            // Γ(n) = (n - 1)!, which is exact in an f64 for small n
+            if x.fract() == 0.0 && x <= MAX_EXACT_FACTORIAL + 1.0 {
+                return BigNum::from_f64((2..(x as u32)).fold(1.0, |product, factor| product * factor as f64));
+            }
+
+            let (ln_gamma, is_negative) = BigNum::ln_gamma_f64(x);
+
+            return BigNum {
+                is_negative,
+                exponent: ln_gamma / LN_10
+            };
+        }
+
+        /// Calculates ln|Γ(x)|, which stays finite long after Γ(x) overflows.
+        /// The poles at non-positive integers give Infinity.
+        pub fn ln_gamma(&self) -> BigNum {
+            if self.is_nan() {
+                return BigNum::NAN;
+            }
+
+            if self.is_infinite() || self.is_zero() {
+                return BigNum::INFINITY;
+            }
+
+            // ln|Γ(x)| = -ln|x| for tiny x
+            if self.exponent < TINY_EXPONENT {
+                return -self.abs_ln();
+            }
+
+            // ln Γ(x) = x (ln(x) - 1), as the other terms of Stirling's series are negligible this far out
+            if !self.is_negative && self.exponent > ASYMPTOTIC_EXPONENT {
+                return BigNum {
+                    is_negative: false,
+                    exponent: self.exponent + (self.exponent * LN_10 - 1.0).log10()
+                };
+            }
+
+            let x = match self.snapped_f64() {
+                Some(x) => x,
+                None => return BigNum::INFINITY
+            };
+
+            if x < 0.0 && x.fract() == 0.0 {
+                return BigNum::INFINITY;
+            }
+
+            return BigNum::from_f64(BigNum::ln_gamma_f64(x).0);
+        }
+
+        /// Calculates n!, which is Γ(n + 1) for non-integers.
+        pub fn factorial(&self) -> BigNum {
+            return (*self + BigNum::ONE).gamma();
+        }
+
+        /// Calculates the principal branch of the Lambert W function, the number w >= -1 where w e^w = self.
+        /// Only defined for values of at least -1/e.
+        pub fn lambert_w0(&self) -> BigNum {
+            if self.is_nan() || (self.is_infinite() && self.is_negative) {
+                return BigNum::NAN;
+            }
+
+            if self.is_infinite() || self.is_zero() || self.exponent < LAMBERT_LINEAR_EXPONENT {
+                return *self;
+            }
+
+            if self.is_negative {
+                return match branch_argument(self) {
+                    Some(x) => BigNum::from_f64(BigNum::lambert_w0_f64(x)),
+                    None => BigNum::NAN
+                };
+            }
+
+            let ln_x = self.exponent * LN_10;
+
+            if ln_x < 1.0 {
+                return BigNum::from_f64(BigNum::lambert_w0_f64(10_f64.powf(self.exponent)));
+            }
+
+            if ln_x.is_finite() {
+                return BigNum::from_f64(BigNum::lambert_w0_from_ln(ln_x));
+            }
+
+            return lambert_w_asymptotic(self.exponent, false);
+        }
+
+        /// Calculates the lower branch of the Lambert W function, the number w <= -1 where w e^w = self.
+        /// Only defined between -1/e and 0, and goes to -Infinity at 0.
+        pub fn lambert_wm1(&self) -> BigNum {
+            if self.is_zero() {
+                return BigNum::NEG_INFINITY;
+            }
+
+            if self.is_nan() || self.is_infinite() || !self.is_negative {
+                return BigNum::NAN;
+            }
+
+            let ln_x = self.exponent * LN_10;
+
+            if !ln_x.is_finite() {
+                return lambert_w_asymptotic(self.exponent, true);
+            }
+
+            // Away from the branch point (|x| < 0.22), w + ln(-w) = ln(-x) is better behaved than w e^w = x
+            if ln_x < -1.5 {
+                return BigNum::from_f64(lambert_w_from_ln(ln_x, ln_x - (-ln_x).ln()));
+            }
+
+            return match branch_argument(self) {
+                Some(x) => BigNum::from_f64(lambert_w_halley(x, branch_point_guess(x, true))),
+                None => BigNum::NAN
+            };
+        }
+
+        /// The principal branch of the Lambert W function in f64, for z >= -1/e.
+        pub(crate) fn lambert_w0_f64(z: f64) -> f64 {
+            if z < E {
+                let guess = if z < -0.25 { branch_point_guess(z, false) } else { z.ln_1p() };
+                return lambert_w_halley(z, guess);
+            }
+
+            return BigNum::lambert_w0_from_ln(z.ln());
+        }
+
+        /// The principal branch of the Lambert W function in f64, for z >= e, given ln(z).
+        pub(crate) fn lambert_w0_from_ln(ln_z: f64) -> f64 {
+            return lambert_w_from_ln(ln_z, (ln_z - ln_z.ln()).max(1.0));
+        }
+
+        /// Converts to an f64, rounding to the nearest integer if it's within rounding error of one.
+        pub(crate) fn snapped_f64(&self) -> Option<f64> {
+            return self.to_f64().map(snap_to_integer);
+        }
+
+        /// Returns ln|Γ(x)| and whether Γ(x) is negative, for finite x that isn't a pole.
+        pub(crate) fn ln_gamma_f64(x: f64) -> (f64, bool) {
+            if x < 0.5 {
+                // Reflection formula: Γ(x) Γ(1 - x) = π / sin(πx)
+                // x % 2 keeps the argument of sin small, so it stays accurate
+                let sin = (PI * (x % 2.0)).sin();
+                let (ln_reflected, _) = BigNum::ln_gamma_f64(1.0 - x);
+
+                return ((PI / sin.abs()).ln() - ln_reflected, sin < 0.0);
+            }
+
+            // Γ(x) = Γ(x + n) / (x (x + 1) ... (x + n - 1))
+            let mut x = x;
+            let mut shift = 1.0;
+
+            while x < STIRLING_MIN {
+                shift *= x;
+                x += 1.0;
+            }
+
+            return ((x - 0.5) * x.ln() - x + HALF_LN_TAU + stirling_series(x) - shift.ln(), false);
+        }
+    }
+
+    // Stirling's series, ln Γ(x) - ((x - 1/2) ln(x) - x + ln(2π) / 2), with the Bernoulli numbers B2 to B10
+    pub(crate) fn stirling_series(x: f64) -> f64 {
+        let inverse = 1.0 / x;
+        let inverse_squared = inverse * inverse;
+
+        return inverse * (1.0 / 12.0
+            - inverse_squared * (1.0 / 360.0
+            - inverse_squared * (1.0 / 1260.0
+            - inverse_squared * (1.0 / 1680.0
+            - inverse_squared / 1188.0))));
+    }
+
+    // The argument as an f64 if it's at least -1/e, allowing for the rounding error of the exponent
+    fn branch_argument(value: &BigNum) -> Option<f64> {
+        let x = value.to_f64()?;
+
+        if x < -INV_E * (1.0 + 4.0 * f64::EPSILON) {
+            return None;
+        }
+
+        return Some(x.max(-INV_E));
+    }
+
+    // Series around the branch point at -1/e, for either branch
+    fn branch_point_guess(z: f64, lower: bool) -> f64 {
+        let p = (2.0 * (E * z + 1.0)).max(0.0).sqrt();
+        let p = if lower { -p } else { p };
+
+        return -1.0 + p - p * p / 3.0 + 11.0 / 72.0 * p * p * p;
+    }
+
+    // Halley's method on w e^w - z
+    fn lambert_w_halley(z: f64, guess: f64) -> f64 {
+        let mut w = guess;
+
+        for _ in 0..50 {
+            let ew = w.exp();
+            let f = w * ew - z;
+
+            if f == 0.0 || w == -1.0 {
+                break;
+            }
+
+            let next = w - f / (ew * (w + 1.0) - (w + 2.0) * f / (2.0 * w + 2.0));
+
+            if (next - w).abs() <= f64::EPSILON * next.abs() {
+                return next;
+            }
+
+            w = next;
+        }
+
+        return w;
+    }
+
+    // Newton's method on w + ln|w| - ln|z|, which works on either branch as long as w stays away from -1
+    fn lambert_w_from_ln(ln_z: f64, guess: f64) -> f64 {
+        let mut w = guess;
+
+        for _ in 0..50 {
+            let next = w * (1.0 + ln_z - w.abs().ln()) / (1.0 + w);
+
+            if (next - w).abs() <= f64::EPSILON * next.abs() {
+                return next;
+            }
+
+            w = next;
+        }
+
+        return w;
+    }
+
+    // W(x) = L1 - L2 + L2 / L1 + ..., where L1 = ln|x| and L2 = ln|L1|, for x whose logarithm overflows an f64.
+    // log10|W| = log10|L1| + log10(1 - L2 / L1 + ...), where the correction is tiny this far out
+    fn lambert_w_asymptotic(exponent: f64, lower: bool) -> BigNum {
+        let log10_l1 = exponent.abs().log10() + LN_10.log10();
+        let ratio = log10_l1 * LN_10 * 10_f64.powf(-log10_l1);
+        let correction = if lower { ratio.ln_1p() } else { (-ratio).ln_1p() };
+
+        return BigNum {
+            is_negative: lower,
+            exponent: log10_l1 + correction / LN_10
+        };
+    }
+}
\ No newline at end of file
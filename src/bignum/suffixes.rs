@@ -0,0 +1,148 @@
+mod suffix {
+    use std::collections::HashMap;
+    use std::sync::OnceLock;
+
+    use crate::bignum::{BigNum, ParseBigNumError};
+
+    // Abbreviations of the Latin prefixes of the -illions, e.g. 'QaDc' for quattuordecillion (n = 14)
+    const FIRST_ILLIONS: [&str; 10] = ["", "M", "B", "T", "Qa", "Qi", "Sx", "Sp", "Oc", "No"];
+    const UNITS: [&str; 10] = ["", "U", "D", "T", "Qa", "Qi", "Sx", "Sp", "Oc", "No"];
+    const TENS: [&str; 10] = ["", "Dc", "Vg", "Tg", "Qag", "Qig", "Sxg", "Spg", "Ocg", "Nog"];
+    const HUNDREDS: [&str; 10] = ["", "Ce", "Dn", "Tc", "Qe", "Qu", "Sc", "Si", "Oe", "Ne"];
+
+    // Millillion, the last -illion with a name
+    const MAX_ILLION: u32 = 1000;
+
+    // Every suffix and its group, built the first time a suffix is parsed
+    static SHORT_SCALE_GROUPS: OnceLock<HashMap<String, u32>> = OnceLock::new();
+    static LONG_SCALE_GROUPS: OnceLock<HashMap<String, u32>> = OnceLock::new();
+
+    // Abbreviated name of the nth -illion, e.g. 'M' for million (n = 1)
+    fn illion_name(n: u32) -> Option<String> {
+        if n == 0 || n > MAX_ILLION {
+            return None;
+        }
+
+        if n == MAX_ILLION {
+            return Some("Mi".into());
+        }
+
+        if n < 10 {
+            return Some(FIRST_ILLIONS[n as usize].into());
+        }
+
+        return Some(format!(
+            "{}{}{}",
+            UNITS[(n % 10) as usize],
+            TENS[(n / 10 % 10) as usize],
+            HUNDREDS[(n / 100) as usize]
+        ));
+    }
+
+    // Suffix for 1000^group, e.g. 'M' for group 2
+    fn suffix_name(group: u32, long_scale: bool) -> Option<String> {
+        if group == 1 {
+            return Some("K".into());
+        }
+
+        if !long_scale {
+            // Short scale: 1000^(n + 1) is the nth -illion
+            return illion_name(group.checked_sub(1)?);
+        }
+
+        // Long scale: 1000000^n is the nth -illion, and 1000 times that is the nth -illiard
+        let name = illion_name(group / 2)?;
+
+        return Some(if group.is_multiple_of(2) { name } else { name + "d" });
+    }
+
+    fn max_group(long_scale: bool) -> u32 {
+        return if long_scale { MAX_ILLION * 2 + 1 } else { MAX_ILLION + 1 };
+    }
+
+    fn suffix_group(suffix: &str, long_scale: bool) -> Option<u32> {
+        let groups = if long_scale { &LONG_SCALE_GROUPS } else { &SHORT_SCALE_GROUPS };
+
+        let groups = groups.get_or_init(|| {
+            let mut groups = HashMap::new();
+
+            for group in 1..=max_group(long_scale) {
+                if let Some(name) = suffix_name(group, long_scale) {
+                    // If two groups ever share a name, the smaller one wins
+                    groups.entry(name).or_insert(group);
+                }
+            }
+
+            return groups;
+        });
+
+        return groups.get(suffix).copied();
+    }
+
+    impl BigNum {
+        /// Formats the number with short scale suffixes, e.g. '1.23 Qa' for 1.23e15.
+        /// Numbers below 1000 and above the last suffix (10^3003) use `to_string_sci` instead.
+        pub fn to_string_suffix(&self, precision: usize) -> String {
+            return self.format_with_suffix(precision, false);
+        }
+
+        /// Formats the number with long scale suffixes, e.g. '1.23 Md' (milliard) for 1.23e9.
+        /// Numbers below 1000 and above the last suffix (10^6003) use `to_string_sci` instead.
+        pub fn to_string_suffix_long(&self, precision: usize) -> String {
+            return self.format_with_suffix(precision, true);
+        }
+
+        fn format_with_suffix(&self, precision: usize, long_scale: bool) -> String {
+            if !self.is_finite() || self.exponent < 3.0 || self.exponent >= 3.0 * (max_group(long_scale) + 1) as f64 {
+                return self.to_string_sci(precision);
+            }
+
+            let (mantissa, group) = self.split_groups(3.0, precision);
+
+            return match suffix_name(group as u32, long_scale) {
+                Some(suffix) => format!(
+                    "{}{:.*} {}",
+                    if self.is_negative { "-" } else { "" },
+                    precision,
+                    mantissa,
+                    suffix
+                ),
+                None => self.to_string_sci(precision)
+            };
+        }
+
+        /// Parses a number with an optional short scale suffix, like '1.5M' or '2.3 Qa'.
+        pub fn from_string_suffix(value: &str) -> Result<BigNum, ParseBigNumError> {
+            return BigNum::from_string_with_suffix(value, false);
+        }
+
+        /// Parses a number with an optional long scale suffix, like '1.5Md' or '2.3 B'.
+        pub fn from_string_suffix_long(value: &str) -> Result<BigNum, ParseBigNumError> {
+            return BigNum::from_string_with_suffix(value, true);
+        }
+
+        fn from_string_with_suffix(value: &str, long_scale: bool) -> Result<BigNum, ParseBigNumError> {
+            let suffix_length = value.bytes().rev().take_while(|b| b.is_ascii_alphabetic()).count();
+            let suffix_start = value.len() - suffix_length;
+
+            if suffix_length == 0 {
+                return BigNum::from_string(value);
+            }
+
+            let group = match suffix_group(&value[suffix_start..], long_scale) {
+                Some(group) => group,
+                // Not a suffix, but it might still be a number like 'inf' or 'e5'
+                None => return BigNum::from_string(value)
+                    .map_err(|_| ParseBigNumError::InvalidSuffix { position: suffix_start })
+            };
+
+            let number = BigNum::from_string(value[..suffix_start].trim_end())?;
+
+            // number * 1000^group
+            return Ok(BigNum {
+                is_negative: number.is_negative,
+                exponent: number.exponent + 3.0 * group as f64
+            });
+        }
+    }
+}
\ No newline at end of file
@@ -351,4 +351,1544 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_layered_conversions() {
+        use crate::bignum::LayeredNum;
+
+        let tests = vec!["0", "-0", "1", "-2.5", "e-500", "e1e200", "-e1.5e308", "inf", "-inf"];
+
+        for input in tests {
+            let value = BigNum::from_string(input).unwrap();
+            let layered = LayeredNum::from_bignum(value);
+
+            assert_eq!(layered.layer, 0, "Layered conversion check: {} (layer check)", input);
+            assert_eq!(layered.to_bignum(), Some(value), "Layered conversion check: {} (round trip)", input);
+        }
+
+        let huge = LayeredNum::from_layers(false, 1, 400.0);
+        assert_eq!(huge.to_bignum(), None);
+
+        // Magnitudes that fit into a lower layer get moved down
+        let small = LayeredNum::from_layers(true, 2, 1.0);
+        assert_eq!(small, LayeredNum { is_negative: true, layer: 0, magnitude: 1e10 });
+    }
+
+    #[test]
+    fn test_layered_arithmetic() {
+        use crate::bignum::LayeredNum;
+
+        let max = LayeredNum::from_bignum(BigNum::MAX);
+
+        // BigNum overflows into infinity here, LayeredNum moves up a layer instead
+        let square = max * max;
+        assert!((BigNum::MAX * BigNum::MAX).is_infinite());
+        assert_eq!(square.layer, 1);
+        assert!(almost_equal(square.magnitude, f64::MAX.log10() + 2_f64.log10(), 1e-9));
+        assert!(almost_equal((square / max).to_bignum().unwrap().exponent, f64::MAX, 1e297));
+
+        let tower = LayeredNum::from_f64(10.0).powb(LayeredNum::from_bignum(BigNum::from_exponent(400.0, false)));
+        assert_eq!(tower.layer, 1);
+        assert!(almost_equal(tower.magnitude, 400.0, 1e-9));
+
+        let taller = LayeredNum::from_f64(10.0).powb(tower);
+        assert_eq!(taller.layer, 2);
+        assert!(almost_equal(taller.magnitude, 400.0, 1e-9));
+        assert!(almost_equal(taller.log10().magnitude, tower.magnitude, 1e-9));
+
+        let tiny = tower.recip();
+        assert_eq!(tiny.layer, 1);
+        assert!(tiny < LayeredNum::from_bignum(BigNum::MIN_POSITIVE));
+        assert!(tiny > LayeredNum::ZERO);
+
+        // The smaller number can't affect a number this large
+        assert_eq!(tower + max, tower);
+        assert_eq!(max - tower, -tower);
+        assert_eq!(tower - tower, LayeredNum::ZERO);
+
+        let sum = LayeredNum::from_f64(2.0) + LayeredNum::from_f64(3.0);
+        assert!(almost_equal(sum.to_f64().unwrap(), 5.0, 1e-9));
+
+        assert_eq!(format!("{}", tower), "e1e400");
+        assert_eq!(format!("{}", -taller), "-ee1e400");
+        assert_eq!(format!("{}", tiny), "e-1e400");
+    }
+
+    #[test]
+    fn test_tetration() {
+        let tests = vec![
+            // Name,            Base,   Height, Expected,       Exponent tolerance
+            ("Zero Height",     "2",    0.0,    "1",            1e-13),
+            ("One Height",      "2",    1.0,    "2",            1e-13),
+            ("Two",             "2",    3.0,    "16",           1e-12),
+            ("Four",            "2",    4.0,    "65536",        1e-12),
+            ("Googolplex-ish",  "10",   3.0,    "e1e10",        1e-3),
+            ("Fractional",      "10",   2.5,    "e1453.0403",   1e-3),
+            ("Half",            "10",   0.5,    "3.16227766",   1e-9),
+            ("Convergent",      "1.2",  1e9,    "1.257734",     1e-6),
+            ("Overflow",        "10",   5.0,    "inf",          0.0),
+            ("Huge Overflow",   "10",   1e300,  "inf",          0.0),
+            ("Slow Divergence", "1.4447", 1e300, "inf",         0.0),
+            ("Plateau",         "1.444667861009767", 20000.0, "2.71801008", 1e-8),
+            ("Long Plateau",    "1.444667861009767", 1e8, "2.7182823", 1e-7),
+            ("Plateau Escape",  "1.444667861009767", 1.2e8, "inf",  0.0),
+            ("Infinite Height", "1.2",  f64::INFINITY, "1.257734", 1e-6),
+            ("Even Oscillation", "0.01", 1e9,   "0.941488",     1e-5),
+            ("Odd Oscillation", "0.01", 1e9 + 1.0, "0.0130925", 1e-5)
+        ];
+
+        for (name, base, height, expected, tolerance) in tests {
+            let base = BigNum::from_string(base).unwrap();
+            let expected = BigNum::from_string(expected).unwrap();
+
+            let result = base.tetrate(height);
+
+            assert!(almost_equal(result.exponent, expected.exponent, tolerance),
+                "Tetration check: {} (got {}, expected {})", name, result, expected);
+        }
+
+        assert!(BigNum::from_f64(-2.0).tetrate(2.0).is_nan());
+        assert!(BigNum::from_f64(2.0).tetrate(-1.5).is_nan());
+
+        // Towers below e^-e never settle, so their infinite height has no value
+        assert!(BigNum::from_f64(0.01).tetrate(f64::INFINITY).is_nan());
+    }
+
+    #[test]
+    fn test_slog() {
+        for height in [-0.5, 0.0, 0.25, 1.0, 1.5, 2.0, 2.75, 3.0] {
+            let tower = BigNum::from_f64(10.0).tetrate(height);
+
+            assert!(almost_equal(tower.slog(10.0), height, 1e-9),
+                "Super-logarithm check: height {} (got {})", height, tower.slog(10.0));
+        }
+
+        assert!(almost_equal(BigNum::from_f64(65536.0).slog(2.0), 4.0, 1e-9));
+        assert!(almost_equal(BigNum::MAX.slog(10.0), 3.0 + 308.25_f64.log10().log10(), 1e-3));
+        assert!(almost_equal(BigNum::ZERO.slog(10.0), -1.0, 1e-13));
+        assert!(BigNum::from_f64(2.0).slog(1.2).is_nan());
+    }
+
+    #[test]
+    fn test_ssqrt() {
+        let tests = vec![
+            // Name,        Input,          Expected,       Exponent tolerance
+            ("One",         "1",            "1",            1e-13),
+            ("Four",        "4",            "2",            1e-13),
+            ("Small",       "0.8",          "0.73953365",   1e-6),
+            ("Minimum",     "0.69220063",   "0.36787944",   1e-3),
+            ("Cube",        "27",           "3",            1e-13),
+            ("Ten",         "e10",          "10",           1e-13),
+            ("Huge",        "e1e300",       "e297.526474",  1e-6),
+            ("Max",         "e1.79e308",    "e305.767462",  1e-6)
+        ];
+
+        for (name, input, expected, tolerance) in tests {
+            let input = BigNum::from_string(input).unwrap();
+            let expected = BigNum::from_string(expected).unwrap();
+
+            let result = input.ssqrt();
+
+            assert!(almost_equal(result.exponent, expected.exponent, tolerance),
+                "Super square root check: {} (got {}, expected {})", name, result, expected);
+        }
+
+        assert!(BigNum::from_f64(0.5).ssqrt().is_nan());
+        assert!(BigNum::from_f64(-4.0).ssqrt().is_nan());
+    }
+
+    #[test]
+    fn test_hybrid_exactness() {
+        use crate::bignum::HybridNum;
+
+        let two = HybridNum::from_f64(2.0);
+        let four = two + two;
+
+        assert_eq!(four, HybridNum::Exact(4));
+
+        let tests = vec![
+            // Name,        Result,                                                     Expected
+            ("Sub",         HybridNum::Exact(10) - HybridNum::Exact(25),                HybridNum::Exact(-15)),
+            ("Mul",         HybridNum::Exact(21) * HybridNum::Exact(-25),               HybridNum::Exact(-525)),
+            ("Div",         HybridNum::Exact(2604) / HybridNum::Exact(-62),             HybridNum::Exact(-42)),
+            ("Rem",         HybridNum::Exact(-10) % HybridNum::Exact(3),                HybridNum::Exact(-1)),
+            ("Pow",         HybridNum::Exact(3).powi(20),                               HybridNum::Exact(3486784401)),
+            ("Floor",       HybridNum::Exact(7).floor(),                                HybridNum::Exact(7)),
+            ("Round",       HybridNum::from_f64(2.5).round(),                           HybridNum::Exact(3)),
+            ("Below Limit", HybridNum::Exact((1 << 53) - 2) + HybridNum::Exact(1),      HybridNum::Exact((1 << 53) - 1)),
+            ("f64",         HybridNum::Exact(1) + 2.0 * HybridNum::Exact(3),            HybridNum::Exact(7))
+        ];
+
+        for (name, result, expected) in tests {
+            assert_eq!(result, expected, "Hybrid exactness check: {}", name);
+        }
+
+        // Results that aren't integers below 2^53 fall back to the log representation
+        assert!(!(HybridNum::Exact(1 << 52) * HybridNum::Exact(4)).is_exact());
+        assert!(!(HybridNum::Exact(1) / HybridNum::Exact(3)).is_exact());
+        assert!(!HybridNum::from_i64(i64::MAX).is_exact());
+        assert!(!HybridNum::from_i64(i64::MIN).is_exact());
+
+        // Log-domain results are never exact, even if they round to an integer, unless asked to snap
+        let seven = HybridNum::Exact(7).cbrt().powi(3);
+        assert!(!seven.is_exact());
+        assert_eq!(seven.round_to_exact(), HybridNum::Exact(7));
+        assert!(!HybridNum::from_f64(2_f64.sqrt()).powi(2).is_exact());
+        assert!(!(0.1 * HybridNum::Exact(30)).is_exact());
+        assert!(!HybridNum::from_f64(2.5).round_to_exact().is_exact());
+
+        let roots = vec![
+            // Name,        Result,                                                     Expected
+            ("Square",      HybridNum::Exact(1 << 52).sqrt(),                           HybridNum::Exact(1 << 26)),
+            ("Cube",        HybridNum::Exact(-27).cbrt(),                               HybridNum::Exact(-3)),
+            ("Powb",        HybridNum::Exact(2).powb(HybridNum::Exact(40)),             HybridNum::Exact(1 << 40))
+        ];
+
+        for (name, result, expected) in roots {
+            assert_eq!(result, expected, "Hybrid exactness check: {}", name);
+        }
+
+        assert!(!HybridNum::Exact(50).sqrt().is_exact());
+        assert!(!HybridNum::Exact(i64::MAX).cbrt().is_exact());
+        assert!(HybridNum::Exact(-4).sqrt().is_nan());
+
+        let mut float = 1.5;
+        float += HybridNum::Exact(2);
+        float *= HybridNum::Exact(4);
+        assert_eq!(float, 14.0);
+
+        let third = HybridNum::Exact(1) / HybridNum::Exact(3);
+        assert!(almost_equal((third * HybridNum::Exact(3)).to_f64().unwrap(), 1.0, 1e-12));
+        assert_eq!((HybridNum::Exact(10) / HybridNum::Exact(4)).floor(), HybridNum::Exact(2));
+
+        assert!(HybridNum::Exact(3) < HybridNum::Exact(4));
+        assert!(HybridNum::Exact(3) > HybridNum::from_f64(2.5));
+        assert_eq!(format!("{}", HybridNum::Exact(-12345)), "-12345");
+    }
+
+    #[test]
+    fn test_from_string_long_decimals() {
+        let huge = format!("1{}", "0".repeat(399));
+        let huge_fraction = format!("-25{}.5", "0".repeat(400));
+        let tiny = format!("0.{}1", "0".repeat(3000));
+        let tiny_integer = format!("000{}", tiny);
+        let huge_sci = format!("{}e-400", huge);
+        let precise = "3.14159265358979323846264338327950288419716939937510";
+
+        let tests = vec![
+            // Name,            Input,              Expected exponent,              Expected sign
+            ("Huge",            huge.as_str(),      399.0,                          false),
+            ("Huge Fraction",   &huge_fraction,     401.0 + 2.5_f64.log10(),        true),
+            ("Tiny",            &tiny,              -3001.0,                        false),
+            ("Leading Zeros",   &tiny_integer,      -3001.0,                        false),
+            ("Long Fraction",   precise,            std::f64::consts::PI.log10(),   false),
+            ("Long Zero",       "0.000000000000",   f64::NEG_INFINITY,              false),
+            ("Sci. Long",       &huge_sci,          -1.0,                           false)
+        ];
+
+        for (name, input, expected_exponent, expected_is_negative) in tests {
+            let decimal = BigNum::from_string(input).unwrap();
+
+            assert_eq!(decimal.is_negative, expected_is_negative, "{}: sign check", name);
+            assert!(almost_equal(decimal.exponent, expected_exponent, 1e-12),
+                "{}: exponent check (got {}, expected {})", name, decimal.exponent, expected_exponent);
+        }
+
+        let parsed: BigNum = huge.parse().unwrap();
+        assert_eq!(parsed.exponent, 399.0);
+
+        assert!(BigNum::from_string("1.2.3").is_err());
+        assert!(BigNum::from_string(".").is_err());
+        assert!(BigNum::from_string(&format!("{}x", huge)).is_err());
+    }
+
+    #[test]
+    fn test_from_string_towers() {
+        let tests = vec![
+            // Name,            Input,          Expected exponent,  Expected sign
+            ("Log Tower",       "e1e10",        1e10,               false),
+            ("Log Neg. Tower",  "-e-1e20",      -1e20,              true),
+            ("Log Plus",        "e+1e+10",      1e10,               false),
+            ("Sci. Tower",      "3e1.23e45",    1.23e45,            false),
+            ("Triple",          "1e1e1e2",      1e100,              false),
+            ("Triple Log",      "ee1e2",        1e100,              false),
+            ("Triple Coeff.",   "1e2e1e1",      2e10,               false),
+            ("Triple Neg.",     "-1e-1e1e2",    -1e100,             true),
+            ("Triple Mixed",    "1e1e-1e1",     1e-10,              false),
+            ("Zero Tower",      "0e1e1e5",      f64::NEG_INFINITY,  false),
+            ("Overflow",        "1e1e1e5",      f64::INFINITY,      false),
+            ("Underflow",       "1e-1e1e5",     f64::NEG_INFINITY,  false)
+        ];
+
+        for (name, input, expected_exponent, expected_is_negative) in tests {
+            let decimal = BigNum::from_string(input).unwrap();
+
+            assert_eq!(decimal.is_negative, expected_is_negative, "{}: sign check", name);
+            assert!(almost_equal(decimal.exponent, expected_exponent, expected_exponent.abs() * 1e-12),
+                "{}: exponent check (got {}, expected {})", name, decimal.exponent, expected_exponent);
+        }
+
+        for input in ["1e1e", "1e1e-", "ee", "1e1.2.3e4", "1e1ex", "1ee"] {
+            assert!(BigNum::from_string(input).is_err(), "{}: invalid tower check", input);
+        }
+    }
+
+    #[test]
+    fn test_formatter_round_trips() {
+        let tests = vec![
+            // Name,                Exponent,       Negative,   Precision,  Exponent tolerance
+            ("Zero",                f64::NEG_INFINITY, false,   2,          0.0),
+            ("Infinity",            f64::INFINITY,  false,      2,          0.0),
+            ("Neg. Infinity",       f64::INFINITY,  true,       2,          0.0),
+            ("Number",              2.0913,         false,      2,          5e-3),
+            ("Number Neg.",         2.0913,         true,       2,          5e-3),
+            ("Number Small",        -2.5,           false,      6,          1e-3),
+            ("Number Large",        8.4,            false,      0,          0.5),
+            ("Single",              456.0899,       false,      2,          5e-3),
+            ("Single Neg.",         456.0899,       true,       2,          5e-3),
+            ("Single Tiny",         -456.0899,      false,      2,          5e-3),
+            ("Single Edge",         999999999.5,    false,      3,          1.0),
+            ("Double",              1.2345e45,      false,      2,          1e43),
+            ("Double Neg.",         1.2345e45,      true,       2,          1e43),
+            ("Double Tiny",         -1.2345e45,     false,      2,          1e43),
+            ("Double Tiny Neg.",    -1.2345e45,     true,       2,          1e43),
+            ("Huge",                1.5e308,        false,      4,          1e305),
+            ("Huge Tiny Neg.",      -1.5e308,       true,       4,          1e305),
+            ("Min Positive",        f64::MIN_POSITIVE, false,   4,          1e-4)
+        ];
+
+        for (name, exponent, is_negative, precision, tolerance) in tests {
+            let value = BigNum::from_exponent(exponent, is_negative);
+
+            for string in [value.to_string_sci(precision), value.to_string_log(precision)] {
+                let parsed = BigNum::from_string(&string)
+                    .unwrap_or_else(|e| panic!("Round trip check: {} (failed to parse '{}': {})", name, string, e));
+
+                if !value.is_zero() {
+                    assert_eq!(parsed.is_negative, value.is_negative, "Round trip check: {} (sign check for '{}')", name, string);
+                }
+
+                assert!(almost_equal(parsed.exponent, value.exponent, tolerance),
+                    "Round trip check: {} (exponent check for '{}': got {}, expected {})", name, string, parsed.exponent, value.exponent);
+            }
+        }
+
+        assert!(BigNum::from_string(&BigNum::NAN.to_string_sci(2)).unwrap().is_nan());
+        assert!(BigNum::from_string(&BigNum::NAN.to_string_log(2)).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        use crate::bignum::ParseBigNumError as E;
+
+        let tests = vec![
+            // Input,       Expected error
+            ("",            E::Empty),
+            ("-",           E::InvalidMantissa { position: 1 }),
+            ("--1",         E::InvalidSign { position: 1 }),
+            ("+-1",         E::InvalidSign { position: 1 }),
+            ("abc",         E::InvalidMantissa { position: 0 }),
+            (".",           E::InvalidMantissa { position: 0 }),
+            ("-.e5",        E::InvalidMantissa { position: 1 }),
+            ("e",           E::InvalidExponent { position: 1 }),
+            ("1e",          E::InvalidExponent { position: 2 }),
+            ("1e-",         E::InvalidExponent { position: 3 }),
+            ("1e+",         E::InvalidExponent { position: 3 }),
+            ("1e--5",       E::InvalidSign { position: 3 }),
+            ("1e1e",        E::InvalidExponent { position: 4 }),
+            ("1ex",         E::InvalidExponent { position: 2 }),
+            ("1.5abc",      E::TrailingCharacters { position: 3 }),
+            ("1.2.3",       E::TrailingCharacters { position: 3 }),
+            ("1e5 ",        E::TrailingCharacters { position: 3 }),
+            ("1e1.2.3e4",   E::TrailingCharacters { position: 5 }),
+            ("e1e10x",      E::TrailingCharacters { position: 5 })
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(BigNum::from_string(input), Err(expected), "Parse error check: '{}'", input);
+        }
+
+        assert_eq!(E::InvalidSign { position: 1 }.to_string(), "invalid sign at byte 1");
+    }
+
+    #[test]
+    fn test_from_str_and_try_from() {
+        let parsed: Result<BigNum, _> = "1.5e1e2".parse();
+        assert!(almost_equal(parsed.unwrap().exponent, 100.0 + 1.5_f64.log10(), 1e-12));
+
+        let converted = BigNum::try_from("-e-5").unwrap();
+        assert_eq!(converted, BigNum::from_exponent(-5.0, true));
+
+        for input in ["1.", ".5", "1.e5", "+1", "+e5", "5E+5", "1e.5"] {
+            assert!(BigNum::try_from(input).is_ok(), "Valid input check: '{}'", input);
+        }
+
+        assert!(BigNum::try_from("1e5e").is_err());
+        assert!("".parse::<BigNum>().is_err());
+    }
+
+    #[test]
+    fn test_suffix_notation() {
+        let tests = vec![
+            // (exponent,                        short,        long)
+            (3.0,                                "1.00 K",     "1.00 K"),
+            (1.5e6_f64.log10(),                  "1.50 M",     "1.50 M"),
+            (9.0,                                "1.00 B",     "1.00 Md"),
+            (12.0,                               "1.00 T",     "1.00 B"),
+            (2.3e15_f64.log10(),                 "2.30 Qa",    "2.30 Bd"),
+            (33.0,                               "1.00 Dc",    "1.00 Qid"),
+            (36.0,                               "1.00 UDc",   "1.00 Sx"),
+            (303.0,                              "1.00 Ce",    "1.00 Qigd"),
+            (3003.0,                             "1.00 Mi",    "1.00 Qud"),
+            (999999.999_f64.log10(),             "1.00 M",     "1.00 M"),
+            (6000.0,                             "1.00e6000",  "1.00 Mi"),
+            (6003.0,                             "1.00e6003",  "1.00 Mid"),
+            (6006.0,                             "1.00e6006",  "1.00e6006"),
+            (2.0,                                "100.00",     "100.00")
+        ];
+
+        for (exponent, short, long) in tests {
+            let value = BigNum::from_exponent(exponent, false);
+            assert_eq!(value.to_string_suffix(2), short, "Short scale check: e{}", exponent);
+            assert_eq!(value.to_string_suffix_long(2), long, "Long scale check: e{}", exponent);
+        }
+
+        assert_eq!(BigNum::from_f64(-4.5e7).to_string_suffix(1), "-45.0 M");
+        assert_eq!(BigNum::INFINITY.to_string_suffix(2), "inf");
+    }
+
+    #[test]
+    fn test_suffix_names_are_unique() {
+        for long_scale in [false, true] {
+            let mut names = std::collections::HashSet::new();
+            let mut exponent = 3.0;
+
+            loop {
+                let value = BigNum::from_exponent(exponent, false);
+                let formatted = if long_scale { value.to_string_suffix_long(0) } else { value.to_string_suffix(0) };
+
+                let name = match formatted.split_once(' ') {
+                    Some((_, name)) => name.to_string(),
+                    None => break
+                };
+
+                assert!(names.insert(name.clone()), "Duplicate suffix: '{}'", name);
+                exponent += 3.0;
+            }
+
+            assert_eq!(names.len(), if long_scale { 2001 } else { 1001 });
+        }
+    }
+
+    #[test]
+    fn test_from_string_suffix() {
+        use crate::bignum::ParseBigNumError as E;
+
+        let tests = vec![
+            // (input,        expected exponent,              long scale)
+            ("1.5M",          1.5e6_f64.log10(),              false),
+            ("2.3Qa",         2.3e15_f64.log10(),             false),
+            ("2.3 Qa",        2.3e15_f64.log10(),             false),
+            ("7K",            7e3_f64.log10(),                false),
+            ("1 Mi",          3003.0,                         false),
+            ("1e5 M",         11.0,                           false),
+            ("1.5Md",         1.5e9_f64.log10(),              true),
+            ("1 B",           12.0,                           true),
+            ("1 Mid",         6003.0,                         true),
+            ("42",            42_f64.log10(),                 false)
+        ];
+
+        for (input, expected, long_scale) in tests {
+            let parsed = if long_scale {
+                BigNum::from_string_suffix_long(input)
+            } else {
+                BigNum::from_string_suffix(input)
+            }.unwrap();
+
+            assert!(almost_equal(parsed.exponent, expected, 1e-12), "Suffix parse check: '{}'", input);
+        }
+
+        assert!(BigNum::from_string_suffix("-4 K").unwrap().is_negative);
+        assert_eq!(BigNum::from_string_suffix("inf"), Ok(BigNum::INFINITY));
+        assert_eq!(BigNum::from_string_suffix("1.5 Xy"), Err(E::InvalidSuffix { position: 4 }));
+        assert_eq!(BigNum::from_string_suffix("1.5Md"), Err(E::InvalidSuffix { position: 3 }));
+        assert_eq!(BigNum::from_string_suffix("M"), Err(E::Empty));
+
+        let value = BigNum::from_exponent(1234.5678, false);
+        let round_trip = BigNum::from_string_suffix(&value.to_string_suffix(10)).unwrap();
+        assert!(almost_equal(round_trip.exponent, value.exponent, 1e-9));
+    }
+
+    #[test]
+    fn test_notations() {
+        use crate::bignum::notation::{find_notation, BUILTIN_NOTATIONS};
+
+        let tests = vec![
+            // (notation,       exponent,                      expected)
+            ("scientific",      45.0 + 1.23_f64.log10(),       "1.23e45"),
+            ("engineering",     45.0 + 1.23_f64.log10(),       "1.23e45"),
+            ("engineering",     7.0 + 1.234_f64.log10(),       "12.34e6"),
+            ("engineering",     5.0 + 9.99999_f64.log10(),     "1.00e6"),
+            ("engineering",     -5.0 + 1.5_f64.log10(),        "15.00e-6"),
+            ("engineering",     2.0,                           "100.00"),
+            ("logarithm",       45.5,                          "e45.50"),
+            ("standard",        15.0 + 1.23_f64.log10(),       "1.23 Qa"),
+            ("letters",         3.0,                           "1.00a"),
+            ("letters",         78.0 + 5_f64.log10(),          "5.00z"),
+            ("letters",         81.0,                          "1.00aa"),
+            ("letters",         84.0,                          "1.00ab"),
+            ("letters",         3.0 * 702.0,                   "1.00zz"),
+            ("letters",         3.0 * 703.0,                   "1.00aaa"),
+            ("letters",         1.0,                           "10.00"),
+            ("hyper-e",         45.5,                          "E45.50"),
+            ("hyper-e",         1e100,                         "E100.00#2"),
+            ("hyper-e",         0.5,                           "3.16"),
+            ("infinity",        308.25471555991675 * 1.5,      "1.50∞"),
+            ("infinity",        100.0,                         "1.00e100")
+        ];
+
+        for (name, exponent, expected) in tests {
+            let notation = find_notation(name).unwrap();
+            assert_eq!(notation.format(&BigNum::from_exponent(exponent, false), 2), expected, "Notation check: {} e{}", name, exponent);
+        }
+
+        // Edge cases are shared by every notation
+        for notation in BUILTIN_NOTATIONS {
+            assert_eq!(notation.format(&BigNum::NAN, 2), "NaN", "NaN check: {}", notation.name());
+            assert_eq!(notation.format(&BigNum::ZERO, 2), "0.00", "Zero check: {}", notation.name());
+            assert_eq!(notation.format(&BigNum::INFINITY, 2), "inf", "Infinity check: {}", notation.name());
+            assert_eq!(notation.format(&BigNum::NEG_INFINITY, 2), "-inf", "Negative infinity check: {}", notation.name());
+            assert!(notation.format(&BigNum::from_f64(-5e20), 2).starts_with('-'), "Sign check: {}", notation.name());
+        }
+
+        assert!(find_notation("Hyper-E").is_some());
+        assert!(find_notation("roman").is_none());
+    }
+
+    #[test]
+    fn test_format_specifiers() {
+        let value = BigNum::from_f64(1234.5678);
+        let negative = BigNum::from_f64(-1234.5678);
+        let huge = BigNum::from_exponent(1e7 + 0.5, false);
+
+        let tests = vec![
+            // (formatted,                          expected)
+            (format!("{}", value),                  "1234.57"),
+            (format!("{:.1}", value),               "1234.6"),
+            (format!("{:+}", value),                "+1234.57"),
+            (format!("{:+}", negative),             "-1234.57"),
+            (format!("{:>10.1}", value),            "    1234.6"),
+            (format!("{:<10.1}|", value),           "1234.6    |"),
+            (format!("{:*^10.0}", value),           "***1235***"),
+            (format!("{:010.1}", negative),         "-0001234.6"),
+            (format!("{:.5}", huge),                "e10000000.50000"),
+            (format!("{:#}", value),                "1234.57 (e3.092)"),
+            (format!("{:#.1}", negative),           "-1234.6 (e3.1)"),
+            (format!("{:>6}", BigNum::NAN),         "   NaN"),
+            (format!("{:+}", BigNum::INFINITY),     "+inf"),
+            (format!("{:#}", BigNum::ZERO),         "0"),
+            (format!("{}", -BigNum::ZERO),          "0"),
+            (format!("{:+}", -BigNum::ZERO),        "+0"),
+            (format!("{:.2e}", value),              "1.23e3"),
+            (format!("{:.2E}", negative),           "-1.23E3"),
+            (format!("{:.2e}", BigNum::from_exponent(499.99999, false)), "1.00e500"),
+            (format!("{:.1e}", BigNum::from_f64(0.00025)), "2.5e-4"),
+            (format!("{:e}", BigNum::ZERO),         "0e0"),
+            (format!("{:e}", -BigNum::ZERO),        "0e0"),
+            (format!("{:>12.3e}", value),           "     1.235e3"),
+            (format!("{:E}", BigNum::NEG_INFINITY), "-inf")
+        ];
+
+        for (formatted, expected) in tests {
+            assert_eq!(formatted, expected);
+        }
+
+        let shortest = format!("{:e}", value);
+        assert!(shortest.starts_with("1.23456") && shortest.ends_with("e3"), "Shortest exp check: {}", shortest);
+    }
+
+    #[test]
+    fn test_engineering_and_si() {
+        let tests = vec![
+            // (value,                                         eng,            si)
+            (BigNum::from_f64(1.234e8),                        "123.40e6",     "123.40 Mg"),
+            (BigNum::from_f64(12.3),                           "12.30",        "12.30 g"),
+            (BigNum::from_f64(-4.5e24),                        "-4.50e24",     "-4.50 Yg"),
+            (BigNum::from_f64(999.999),                        "1.00e3",       "1.00 kg"),
+            (BigNum::from_f64(0.0123),                         "12.30e-3",     "12.30 mg"),
+            (BigNum::from_f64(4.5e-6),                         "4.50e-6",      "4.50 µg"),
+            (BigNum::from_f64(1.5e-30),                        "1.50e-30",     "1.50 qg"),
+            (BigNum::from_f64(2e32),                           "200.00e30",    "200.00 Qg"),
+            (BigNum::from_f64(2e33),                           "2.00e33",      "2.00e33 g"),
+            (BigNum::from_f64(2e-31),                          "200.00e-33",   "2.00e-31 g"),
+            (BigNum::from_exponent(2e9, false),                "1e2.00e9",     "1e2.00e9 g"),
+            (BigNum::INFINITY,                                 "inf",          "inf g"),
+            (BigNum::NAN,                                      "NaN",          "NaN g")
+        ];
+
+        for (value, eng, si) in tests {
+            assert_eq!(value.to_string_eng(2), eng, "Engineering check: {:?}", value);
+            assert_eq!(value.to_string_si(2, "g"), si, "SI check: {:?}", value);
+        }
+
+        assert_eq!(BigNum::from_f64(1.5e9).to_string_si(1, ""), "1.5 G");
+        assert_eq!(BigNum::from_f64(150.0).to_string_si(0, ""), "150");
+    }
+
+    #[test]
+    fn test_packed_bignum() {
+        use crate::bignum::PackedBigNum;
+
+        assert_eq!(std::mem::size_of::<PackedBigNum>(), 8);
+        assert_eq!(std::mem::size_of::<BigNum>(), 16);
+
+        let tests = vec![
+            // (value)
+            BigNum::from_f64(1234.5678),
+            BigNum::from_f64(-1234.5678),
+            BigNum::from_exponent(-1e300, true),
+            BigNum::from_exponent(1e300, false),
+            BigNum::ZERO,
+            BigNum::NEG_ONE,
+            BigNum::INFINITY,
+            BigNum::NEG_INFINITY
+        ];
+
+        for value in tests {
+            let unpacked = PackedBigNum::from(value).to_bignum();
+            assert_eq!(unpacked.is_negative, value.is_negative, "Sign check: {:?}", value);
+            assert!(almost_equal(unpacked.exponent, value.exponent, value.exponent.abs() * 1e-15 + 1e-300), "Exponent check: {:?}", value);
+        }
+
+        // NaN is stored canonically, whatever its payload
+        let odd_nan = BigNum::from_exponent(f64::from_bits(0x7FF0_0000_0000_0001), true);
+        assert!(PackedBigNum::from(odd_nan).is_nan());
+        assert!(!PackedBigNum::NAN.is_negative());
+        assert!((-PackedBigNum::NAN).is_nan());
+        assert!(PackedBigNum::NAN != PackedBigNum::NAN);
+
+        let a = PackedBigNum::from(1500.0);
+        let b = PackedBigNum::from(-500.0);
+        assert!(almost_equal((a + b).to_bignum().to_f64().unwrap(), 1000.0, 1e-9));
+        assert!(almost_equal((a * b).to_bignum().to_f64().unwrap(), -750000.0, 1e-6));
+        assert!(almost_equal((a / b).to_bignum().to_f64().unwrap(), -3.0, 1e-12));
+        assert!((-b).to_bignum().is_sign_positive());
+        assert!(b < a);
+        assert_eq!(PackedBigNum::ONE, PackedBigNum::from(1.0));
+        assert_eq!(format!("{:.1}", a), "1500.0");
+
+        let mut total = PackedBigNum::ZERO;
+        total += a;
+        total -= b;
+        assert!(almost_equal(total.to_bignum().to_f64().unwrap(), 2000.0, 1e-9));
+
+        // The same functions as BigNum, giving the same results up to the dropped bit
+        let x = BigNum::from_f64(-1234.5678);
+        let y = BigNum::from_f64(2.5);
+        let packed_x = PackedBigNum::from(x);
+        let packed_y = PackedBigNum::from(y);
+
+        let tests = vec![
+            // (name,       packed,                             expected)
+            ("powb",        packed_y.powb(packed_y),            y.powb(y)),
+            ("powi",        packed_x.powi(3),                   x.powi(3)),
+            ("sqrt",        packed_y.sqrt(),                    y.sqrt()),
+            ("cbrt",        packed_x.cbrt(),                    x.cbrt()),
+            ("abs",         packed_x.abs(),                     x.abs()),
+            ("exp",         packed_y.exp(),                     y.exp()),
+            ("exp10",       packed_y.exp10(),                   y.exp10()),
+            ("log10",       packed_y.log10(),                   y.log10()),
+            ("ln",          packed_y.ln(),                      y.ln()),
+            ("log_b",       packed_y.log_b(packed_y),           y.log_b(y)),
+            ("abs_log10",   packed_x.abs_log10(),               x.abs_log10()),
+            ("hypot",       packed_x.hypot(packed_y),           x.hypot(&y)),
+            ("gamma",       packed_y.gamma(),                   y.gamma()),
+            ("tetrate",     packed_y.tetrate(3.0),              y.tetrate(3.0)),
+            ("floor",       packed_x.floor(),                   x.floor()),
+            ("rem",         packed_x % packed_y,                x % y),
+            ("max",         packed_x.max(packed_y),             x.max(&y)),
+            ("clamp",       packed_x.clamp(packed_y, packed_y * 2.0).unwrap(), x.clamp(&y, &(y * 2.0)).unwrap()),
+            ("f64 add",     packed_x + 1.5,                     x + 1.5),
+            ("f64 mul",     2.0 * packed_y,                     2.0 * y),
+            ("f64 div",     1.0 / packed_x,                     1.0 / x),
+            ("sum",         [packed_x, packed_y].iter().sum(),  [x, y].iter().sum()),
+            ("product",     [packed_x, packed_y].into_iter().product(), [x, y].into_iter().product())
+        ];
+
+        for (name, packed, expected) in tests {
+            let unpacked = packed.to_bignum();
+            assert_eq!(unpacked.is_negative, expected.is_negative, "Packed sign check: {}", name);
+            assert!(almost_equal(unpacked.exponent, expected.exponent, expected.exponent.abs() * 1e-15 + 1e-12), "Packed function check: {} = {}, got {}", name, expected, unpacked);
+        }
+
+        assert!(almost_equal(packed_x.to_f64().unwrap(), -1234.5678, 1e-9));
+        assert!(almost_equal(packed_y.sin(), y.sin(), 1e-12));
+        assert!(almost_equal(packed_x.atan2(packed_y), x.atan2(&y), 1e-12));
+        assert_eq!(packed_x.classify(), x.classify());
+        assert!(packed_x.is_sign_negative() && packed_y.is_sign_positive());
+        assert!(packed_y.almost_equal(packed_y + 1e-9, PackedBigNum::from(1e-6)));
+        assert_eq!(packed_x.to_string_sci(2), x.to_string_sci(2));
+        assert_eq!(PackedBigNum::from_exponent(1e5, false).to_string_log(2), "e100000.00");
+        assert_eq!(PackedBigNum::from_f64(1.5e15).to_string_suffix(2), "1.50 Qa");
+        assert_eq!(PackedBigNum::from_string_suffix("2.5M").unwrap().to_string_num(0).unwrap(), "2500000");
+
+        // The exact text and JSON forms give back the same packed bits
+        for value in [packed_x, packed_y, PackedBigNum::from_exponent(-1e300, true), PackedBigNum::NAN] {
+            assert_eq!(PackedBigNum::from_string(&value.to_string_exact()).unwrap().to_bits(), value.to_bits(), "Packed exact check: {}", value);
+            assert_eq!(PackedBigNum::from_json(&value.to_json()).unwrap().to_bits(), value.to_bits(), "Packed JSON check: {}", value);
+        }
+
+        let mut float = 1.0;
+        float += PackedBigNum::from(2.0);
+        assert!(almost_equal(float, 3.0, 1e-12));
+        assert_eq!("1.5e3".parse::<PackedBigNum>(), Ok(PackedBigNum::from(BigNum::from_string("1.5e3").unwrap())));
+        assert!(PackedBigNum::from_string("abc").is_err());
+    }
+
+    #[test]
+    fn test_sortable_encoding() {
+        // In ascending order
+        let values = vec![
+            BigNum::NEG_INFINITY,
+            BigNum::from_exponent(1e300, true),
+            BigNum::from_f64(-1e10),
+            BigNum::from_f64(-1.5),
+            BigNum::NEG_ONE,
+            BigNum::from_f64(-0.25),
+            BigNum::from_exponent(-1e300, true),
+            BigNum::from_exponent(f64::NEG_INFINITY, true),
+            BigNum::ZERO,
+            BigNum::from_exponent(-1e300, false),
+            BigNum::from_f64(0.25),
+            BigNum::ONE,
+            BigNum::from_f64(1.5),
+            BigNum::from_f64(1e10),
+            BigNum::from_exponent(1e300, false),
+            BigNum::INFINITY
+        ];
+
+        for pair in values.windows(2) {
+            assert!(pair[0] < pair[1], "Test data check: {:?} < {:?}", pair[0], pair[1]);
+            assert!(pair[0].to_sortable_bytes() < pair[1].to_sortable_bytes(), "Byte order check: {:?} < {:?}", pair[0], pair[1]);
+            assert!(pair[0].to_sortable_u64() < pair[1].to_sortable_u64(), "u64 order check: {:?} < {:?}", pair[0], pair[1]);
+        }
+
+        for value in &values {
+            assert_eq!(BigNum::from_sortable_bytes(value.to_sortable_bytes()), Some(*value), "Byte round trip check: {:?}", value);
+
+            let decoded = BigNum::from_sortable_u64(value.to_sortable_u64()).unwrap();
+            assert_eq!(decoded.is_negative, value.is_negative, "u64 sign check: {:?}", value);
+            assert!(almost_equal(decoded.exponent, value.exponent, value.exponent.abs() * 1e-15 + 1e-300), "u64 round trip check: {:?}", value);
+        }
+
+        // NaN goes after everything else
+        assert_eq!(BigNum::NAN.to_sortable_bytes(), [0xFF; 9]);
+        assert_eq!(BigNum::NAN.to_sortable_u64(), u64::MAX);
+        assert!(BigNum::INFINITY.to_sortable_bytes() < BigNum::NAN.to_sortable_bytes());
+        assert!(BigNum::from_sortable_bytes([0xFF; 9]).unwrap().is_nan());
+        assert!(BigNum::from_sortable_u64(u64::MAX).unwrap().is_nan());
+
+        // Invalid encodings
+        assert_eq!(BigNum::from_sortable_bytes([0x02; 9]), None);
+        assert_eq!(BigNum::from_sortable_bytes([0xFF, 0, 0, 0, 0, 0, 0, 0, 0]), None);
+        assert_eq!(BigNum::from_sortable_bytes([0x01, 0xFF, 0xF8, 0, 0, 0, 0, 0, 0]), None);
+    }
+
+    #[test]
+    fn test_binary_encoding() {
+        use crate::bignum::BinaryDecodeError as E;
+
+        let values = vec![
+            BigNum::from_f64(1234.5678),
+            BigNum::from_f64(-0.001),
+            BigNum::from_exponent(1e300, true),
+            BigNum::ZERO,
+            BigNum::INFINITY,
+            BigNum::NEG_INFINITY
+        ];
+
+        for value in &values {
+            assert_eq!(BigNum::from_le_bytes(value.to_le_bytes()), Ok(*value), "Little-endian check: {:?}", value);
+            assert_eq!(BigNum::from_be_bytes(value.to_be_bytes()), Ok(*value), "Big-endian check: {:?}", value);
+        }
+
+        assert_eq!(BigNum::NEG_ONE.to_le_bytes(), [0, 0, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(BigNum::ONE.to_be_bytes()[0], 0);
+        assert!(BigNum::from_le_bytes(BigNum::NAN.to_le_bytes()).unwrap().is_nan());
+
+        let encoded = BigNum::encode_slice(&values);
+        assert_eq!(encoded.len(), 13 + values.len() * 9);
+        assert_eq!(&encoded[..5], b"BGNM\x01");
+        assert_eq!(BigNum::decode_slice(&encoded), Ok(values.clone()));
+        assert_eq!(BigNum::decode_slice(&BigNum::encode_slice(&[])), Ok(vec![]));
+
+        let mut bad_sign = encoded.clone();
+        bad_sign[13 + 9 + 8] = 7;
+
+        let mut bad_version = encoded.clone();
+        bad_version[4] = 99;
+
+        let mut huge_count = encoded.clone();
+        huge_count[5..13].copy_from_slice(&u64::MAX.to_le_bytes());
+
+        let mut trailing = encoded.clone();
+        trailing.push(0);
+
+        let tests = vec![
+            // (bytes,                              expected)
+            (vec![],                                E::InvalidMagic),
+            (b"BGNX".to_vec(),                      E::InvalidMagic),
+            (encoded[..8].to_vec(),                 E::UnexpectedEnd { position: 8 }),
+            (encoded[..20].to_vec(),                E::UnexpectedEnd { position: 20 }),
+            (bad_sign,                              E::InvalidSign { position: 30, value: 7 }),
+            (bad_version,                           E::UnsupportedVersion { version: 99 }),
+            (huge_count,                            E::UnexpectedEnd { position: encoded.len() }),
+            (trailing,                              E::TrailingBytes { position: encoded.len() })
+        ];
+
+        for (bytes, expected) in tests {
+            assert_eq!(BigNum::decode_slice(&bytes), Err(expected), "Decode error check: {:?}", expected);
+        }
+
+        assert_eq!(BigNum::from_be_bytes([2, 0, 0, 0, 0, 0, 0, 0, 0]), Err(E::InvalidSign { position: 0, value: 2 }));
+        assert_eq!(E::InvalidSign { position: 30, value: 7 }.to_string(), "invalid sign byte 0x07 at byte 30");
+    }
+
+    #[test]
+    fn test_exact_round_trips() {
+        let tests = vec![
+            // (value,                                  expected)
+            (BigNum::from_exponent(123.456, false),     "e123.456"),
+            (BigNum::from_exponent(1.5e300, true),      "-e1.5e300"),
+            (BigNum::from_exponent(-1e-7, false),       "e-1e-7"),
+            (BigNum::ONE,                               "e0"),
+            (BigNum::ONE.recip(),                       "e-0"),
+            (BigNum::ZERO,                              "0"),
+            (-BigNum::ZERO,                             "-0"),
+            (BigNum::INFINITY,                          "inf"),
+            (BigNum::NEG_INFINITY,                      "-inf"),
+            (BigNum::NAN,                               "NaN")
+        ];
+
+        for (value, expected) in tests {
+            assert_eq!(value.to_string_exact(), expected);
+        }
+
+        assert_eq!(BigNum::from_string("e-0").unwrap().exponent.to_bits(), (-0.0_f64).to_bits());
+
+        // xorshift64, so every run checks the same values
+        let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            return state;
+        };
+
+        // Signed zero exponents first, since random bits almost never hit them
+        for i in 0..100_004 {
+            let value = match i {
+                0..=3 => BigNum::from_exponent(if i < 2 { 0.0 } else { -0.0 }, i % 2 == 1),
+                _ => BigNum::from_exponent(f64::from_bits(next()), next() & 1 == 1)
+            };
+
+            if value.is_nan() {
+                continue;
+            }
+
+            let text = value.to_string_exact();
+            let parsed = BigNum::from_string(&text).unwrap();
+
+            assert_eq!(parsed.exponent.to_bits(), value.exponent.to_bits(), "Exponent round trip check: '{}'", text);
+            assert_eq!(parsed.is_negative, value.is_negative, "Sign round trip check: '{}'", text);
+        }
+
+        assert!(BigNum::from_string(&BigNum::NAN.to_string_exact()).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_save_codec() {
+        use crate::bignum::{SaveDecodeError as E, SaveValue};
+
+        let values = vec![
+            SaveValue::from(BigNum::from_exponent(1e300 + 0.123, false)),
+            SaveValue::from(BigNum::from_f64(-1234.5678)),
+            SaveValue::from(BigNum::ZERO),
+            SaveValue::from(BigNum::INFINITY),
+            SaveValue::from(0),
+            SaveValue::from(-1),
+            SaveValue::from(300),
+            SaveValue::from(i64::MIN),
+            SaveValue::from(i64::MAX),
+            SaveValue::from(true),
+            SaveValue::from(false)
+        ];
+
+        let encoded = SaveValue::encode(&values);
+        assert!(encoded.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_'), "URL-safe check: {}", encoded);
+        assert_eq!(SaveValue::decode(&encoded), Ok(values));
+
+        for length in 0..4 {
+            let values = vec![SaveValue::Boolean(true); length];
+            assert_eq!(SaveValue::decode(&SaveValue::encode(&values)), Ok(values), "Padding check: {} values", length);
+        }
+
+        // Small integers and booleans stay small
+        assert_eq!(SaveValue::encode(&[SaveValue::Integer(5), SaveValue::Boolean(true)]).len(), 11);
+
+        let mut corrupted = encoded.clone().into_bytes();
+        corrupted[5] = if corrupted[5] == b'A' { b'B' } else { b'A' };
+        assert!(matches!(SaveValue::decode(&String::from_utf8(corrupted).unwrap()), Err(E::ChecksumMismatch { .. })));
+
+        let tests = vec![
+            // (text,           expected)
+            ("",                E::UnexpectedEnd { position: 0 }),
+            ("AQkh",            E::UnexpectedEnd { position: 3 }),
+            ("AQkhH",           E::InvalidLength),
+            ("AQ+hHpsa",        E::InvalidCharacter { position: 2 }),
+            ("AjwMjqE",         E::UnsupportedVersion { version: 2 }),
+            ("AQkhHpsa",        E::UnknownTag { position: 1, tag: 9 })
+        ];
+
+        for (text, expected) in tests {
+            assert_eq!(SaveValue::decode(text), Err(expected), "Decode error check: '{}'", text);
+        }
+
+        assert_eq!(E::UnsupportedVersion { version: 2 }.to_string(), "unsupported save version 2");
+    }
+
+    #[test]
+    fn test_json() {
+        use crate::bignum::{JsonError as E, ParseBigNumError};
+
+        let tests = vec![
+            // (value,                                  json)
+            (BigNum::from_f64(1234.5),                  "1234.5"),
+            (BigNum::from_f64(-0.25),                   "-0.25"),
+            (BigNum::from_f64(1e300),                   "1e300"),
+            (BigNum::ZERO,                              "0"),
+            (BigNum::from_exponent(400.5, false),       "\"e400.5\""),
+            (BigNum::from_exponent(-400.5, true),       "\"-e-400.5\""),
+            (BigNum::INFINITY,                          "\"inf\""),
+            (BigNum::NAN,                               "\"NaN\"")
+        ];
+
+        for (value, json) in tests {
+            assert_eq!(value.to_json(), json, "Encode check: {:?}", value);
+
+            let decoded = BigNum::from_json(json).unwrap();
+            assert!(decoded == value || value.is_nan(), "Decode check: {}", json);
+        }
+
+        // Every number reads back exactly, whether it's written as a number or a string
+        for i in 0..10_000 {
+            let values = [
+                BigNum::from_f64(i as f64 * 1.1),
+                BigNum::from_f64(-1.0 / (i as f64 + 3.0)),
+                BigNum::from_exponent(i as f64 * 0.0617 - 308.0, i % 2 == 0)
+            ];
+
+            for value in values {
+                assert_eq!(BigNum::from_json(&value.to_json()), Ok(value), "Round trip check: {}", value.to_json());
+            }
+        }
+
+        let values = vec![BigNum::from_f64(1.5), BigNum::from_exponent(1e10, false)];
+        let array = BigNum::to_json_array(&values);
+        assert_eq!(array, "[1.5,\"e1e10\"]");
+        assert_eq!(BigNum::from_json_array(&array), Ok(values.clone()));
+        assert_eq!(BigNum::from_json_array(" [ 2 , \"3\" ] "), Ok(vec![BigNum::from_f64(2.0), BigNum::from_f64(3.0)]));
+        assert_eq!(BigNum::from_json_array("[]"), Ok(vec![]));
+
+        let gold = BigNum::from_f64(1.5);
+        let mana = BigNum::from_exponent(400.0, false);
+        let object = BigNum::to_json_object([("gold", &gold), ("mana \"max\"", &mana)]);
+        assert_eq!(object, "{\"gold\":1.5,\"mana \\\"max\\\"\":\"e400\"}");
+        assert_eq!(
+            BigNum::from_json_object(&object),
+            Ok(vec![("gold".to_string(), gold), ("mana \"max\"".to_string(), mana)])
+        );
+        assert_eq!(
+            BigNum::from_json_object("{\"\\u00e9\\ud83d\\ude00\\n\": 1}").unwrap()[0].0,
+            "é😀\n"
+        );
+
+        let tests = vec![
+            // (json,               expected)
+            ("",                    E::UnexpectedEnd),
+            ("1 2",                 E::TrailingCharacters { position: 2 }),
+            ("true",                E::UnexpectedCharacter { position: 0 }),
+            ("\"1e5",               E::UnexpectedEnd),
+            ("\"1x\"",              E::InvalidNumber { position: 0, error: ParseBigNumError::TrailingCharacters { position: 1 } }),
+            ("[1,]",                E::UnexpectedCharacter { position: 3 }),
+            ("[1 2]",               E::UnexpectedCharacter { position: 3 }),
+            ("{\"a\\q\": 1}",       E::InvalidEscape { position: 3 }),
+            ("{\"\\ud83d\": 1}",    E::InvalidEscape { position: 2 }),
+            ("{1: 1}",              E::UnexpectedCharacter { position: 1 }),
+            ("-e5",                 E::UnexpectedCharacter { position: 1 }),
+            ("1e1e5",               E::TrailingCharacters { position: 3 }),
+            ("01",                  E::TrailingCharacters { position: 1 }),
+            ("1.",                  E::UnexpectedEnd),
+            (".5",                  E::UnexpectedCharacter { position: 0 }),
+            ("1e+",                 E::UnexpectedEnd),
+            ("+1",                  E::UnexpectedCharacter { position: 0 }),
+            ("[-]",                 E::UnexpectedCharacter { position: 2 }),
+            ("[1.5e3.2]",           E::UnexpectedCharacter { position: 6 })
+        ];
+
+        // The extended syntax of from_string is still read inside strings
+        assert_eq!(BigNum::from_json("\"e5\""), Ok(BigNum::from_f64(1e5)));
+        assert_eq!(BigNum::from_json("-0.5e-2"), Ok(BigNum::from_f64(-0.005)));
+
+        for (json, expected) in tests {
+            let result = if json.starts_with('[') {
+                BigNum::from_json_array(json).map(|_| ())
+            } else if json.starts_with('{') {
+                BigNum::from_json_object(json).map(|_| ())
+            } else {
+                BigNum::from_json(json).map(|_| ())
+            };
+
+            assert_eq!(result, Err(expected), "Decode error check: '{}'", json);
+        }
+    }
+
+    #[test]
+    fn test_sum_and_product() {
+        let values: Vec<BigNum> = (1..=1000).map(|i| BigNum::from_f64(i as f64 * 1.5)).collect();
+
+        let total: BigNum = values.iter().sum();
+        assert!(almost_equal(total.to_f64().unwrap(), 1.5 * 500500.0, 1e-6));
+
+        // The order doesn't matter
+        let reversed: BigNum = values.iter().rev().copied().sum();
+        assert_eq!(total, reversed);
+
+        // 1e30 + 1 - 1e30 loses the 1 when added one at a time
+        let cancelling = vec![BigNum::from_f64(1e30), BigNum::ONE, BigNum::from_f64(-1e30)];
+        let exact: BigNum = cancelling.iter().sum();
+        assert!(almost_equal(exact.to_f64().unwrap(), 1.0, 1e-9));
+
+        // Small terms that are each too small to count add up together
+        let mut small_terms = vec![BigNum::from_exponent(25.0, false)];
+        small_terms.extend(std::iter::repeat_n(BigNum::from_exponent(4.0, false), 1_000_000));
+        let small_total: BigNum = small_terms.into_iter().sum();
+        assert!(almost_equal(small_total.exponent, (1e25_f64 + 1e10).log10(), 1e-12));
+
+        let tests = vec![
+            // (name,                    values,                                                 expected)
+            ("empty",                    vec![],                                                 BigNum::ZERO),
+            ("zeros",                    vec![BigNum::ZERO, BigNum::ZERO],                       BigNum::ZERO),
+            ("infinity",                 vec![BigNum::ONE, BigNum::INFINITY],                    BigNum::INFINITY),
+            ("negative infinity",        vec![BigNum::NEG_INFINITY, BigNum::ONE],                BigNum::NEG_INFINITY),
+            ("huge",                     vec![BigNum::from_exponent(1e300, false); 2],           BigNum::from_exponent(1e300 + 2_f64.log10(), false))
+        ];
+
+        for (name, values, expected) in tests {
+            assert_eq!(values.into_iter().sum::<BigNum>(), expected, "Sum check: {}", name);
+        }
+
+        assert!(vec![BigNum::INFINITY, BigNum::NEG_INFINITY].into_iter().sum::<BigNum>().is_nan());
+        assert!(vec![BigNum::ONE, BigNum::NAN].into_iter().sum::<BigNum>().is_nan());
+
+        let product: BigNum = vec![BigNum::from_f64(2.0), BigNum::from_f64(-0.5), BigNum::from_f64(3.0)].iter().product();
+        assert!(almost_equal(product.to_f64().unwrap(), -3.0, 1e-12));
+
+        let empty_product: BigNum = Vec::<BigNum>::new().into_iter().product();
+        assert_eq!(empty_product, BigNum::ONE);
+
+        let huge_product: BigNum = std::iter::repeat_n(BigNum::from_exponent(1e300, false), 10).product();
+        assert!(almost_equal(huge_product.exponent, 1e301, 1e285));
+
+        assert!(vec![BigNum::ZERO, BigNum::INFINITY].into_iter().product::<BigNum>().is_nan());
+        assert!(vec![BigNum::ZERO, BigNum::from_f64(5.0)].into_iter().product::<BigNum>().is_zero());
+    }
+
+    #[test]
+    fn test_accumulator() {
+        use crate::bignum::BigAccumulator;
+
+        // Each 1e-21 is dropped by `+`, but a million of them make 1e-15
+        let mut plain = BigNum::ONE;
+        let mut accumulator = BigAccumulator::new(BigNum::ONE);
+
+        for _ in 0..1_000_000 {
+            plain += BigNum::from_exponent(-21.0, false);
+            accumulator += BigNum::from_exponent(-21.0, false);
+        }
+
+        assert_eq!(plain, BigNum::ONE);
+        // log10(1 + 1e-15), within the precision `+` has so close to 1
+        assert!(accumulator.value().exponent > 0.0);
+        assert!(almost_equal(accumulator.value().exponent, 4.342944819032516e-16, 1e-16));
+
+        // A slow generator on a big number
+        let mut resources = BigAccumulator::from(BigNum::from_f64(1e20));
+        for _ in 0..10_000 {
+            resources += 1.0;
+        }
+        resources.flush();
+        // 1e4 is below the resolution of 1e20, so it all stays buffered
+        assert_eq!(resources.value, BigNum::from_f64(1e20));
+        assert!(almost_equal(resources.pending().to_f64().unwrap(), 1e4, 1e-6));
+
+        // Flushing over and over doesn't lose the amounts the value can't hold yet
+        let mut flushed = BigAccumulator::from(BigNum::from_f64(1e20));
+        for _ in 0..1020 {
+            flushed.add(BigNum::from_f64(1e3));
+            flushed.flush();
+        }
+        let gained = (flushed.value - BigNum::from_f64(1e20)).to_f64().unwrap() + flushed.pending().to_f64().unwrap();
+        assert!(almost_equal(gained, 1.02e6, 1e-3), "Accumulator flush check: gained {}", gained);
+
+        // Enough small amounts get flushed into the value on their own
+        let mut flushing = BigAccumulator::new(BigNum::from_f64(1e10));
+        for _ in 0..1000 {
+            flushing += 1.0;
+        }
+        assert!(almost_equal(flushing.value().to_f64().unwrap(), 1e10 + 1000.0, 1e-3));
+
+        let mut mixed = BigAccumulator::default();
+        mixed += 5.0;
+        mixed -= 2.0;
+        assert!(almost_equal(mixed.value().to_f64().unwrap(), 3.0, 1e-9));
+
+        let mut infinite = BigAccumulator::default();
+        infinite += BigNum::INFINITY;
+        infinite += 1.0;
+        assert!(infinite.value().is_infinite());
+    }
+
+    #[test]
+    fn test_interval_arithmetic() {
+        use crate::bignum::{BigInterval, Certainty};
+
+        let a = BigInterval::from_f64(1234.5678);
+        let b = BigInterval::from_f64(-0.25);
+        let c = BigInterval::new(BigNum::from_f64(3.0), BigNum::from_f64(2.0));
+
+        assert_eq!(c.lo, BigNum::from_f64(2.0));
+        assert!(a.contains(&BigNum::from_f64(1234.5678)));
+
+        let tests = vec![
+            // (name,           interval,                   exact,                      tight)
+            ("add",           a + b,                      1234.3178,                  true),
+            ("sub",           a - b,                      1234.8178,                  true),
+            ("cancel",        a - a,                      0.0,                        true),
+            ("mul",           a * b,                      -308.64195,                 true),
+            ("div",           a / b,                      -4938.2712,                 true),
+            ("div range",     a / c,                      1234.5678 / 2.5,            false),
+            ("powf",          a.powf(2.5),                1234.5678_f64.powf(2.5),    true),
+            ("powf negative", b.powf(2.0),                0.0625,                     true),
+            ("powf range",    (b + c * b).powf(2.0),      0.5625,                     false),
+            ("powb",          a.powb(&c),                 1234.5678_f64.powf(2.5),    false),
+            ("log10",         a.log10(),                  1234.5678_f64.log10(),      true),
+            ("sqrt",          a.sqrt(),                   1234.5678_f64.sqrt(),       true)
+        ];
+
+        for (name, interval, exact, tight) in tests {
+            assert!(interval.contains(&BigNum::from_f64(exact)), "Containment check: {} {} ∌ {}", name, interval, exact);
+            assert!(interval.lo <= interval.hi, "Order check: {}", name);
+
+            // The bounds shouldn't be so loose that they're useless
+            let width = (interval.hi - interval.lo).to_f64().unwrap();
+            assert!(width <= exact.abs().max(1.0) * 1e-10 || !tight, "Width check: {} {}", name, interval);
+        }
+
+        // Nearly equal numbers can't be told apart, but clearly different ones can
+        let price = BigInterval::exact(BigNum::from_exponent(100.0, false));
+        let almost = BigInterval::exact(BigNum::from_exponent(100.0 - 1e-14, false)) + BigInterval::exact(BigNum::ZERO);
+        let cheap = BigInterval::exact(BigNum::from_exponent(99.0, false));
+
+        assert_eq!(price.ge(&cheap), Certainty::Definitely);
+        assert_eq!(cheap.ge(&price), Certainty::DefinitelyNot);
+        assert_eq!(cheap.lt(&price), Certainty::Definitely);
+        assert_eq!(almost.ge(&price), Certainty::Maybe);
+        assert_eq!(price.ge(&price), Certainty::Definitely);
+        assert_eq!(price.gt(&price), Certainty::DefinitelyNot);
+        assert!(price.le(&price).is_definite());
+        assert_eq!(BigInterval::NAN.ge(&price), Certainty::Maybe);
+
+        assert_eq!(c.recip().lo, c.recip().lo.min(&BigNum::from_f64(1.0 / 3.0)));
+        assert_eq!((a / (b + BigInterval::from_f64(0.25))), BigInterval::EVERYTHING);
+        assert!(b.sqrt().is_nan());
+        assert!((BigInterval::exact(BigNum::INFINITY) + BigInterval::exact(BigNum::NEG_INFINITY)).is_nan());
+    }
+
+    #[test]
+    fn test_checked_operations() {
+        use crate::bignum::BigNumError as E;
+
+        let two = BigNum::from_f64(2.0);
+        let huge = BigNum::from_exponent(f64::MAX, false);
+        let tiny = BigNum::from_exponent(-f64::MAX, false);
+
+        let tests = vec![
+            // (name,                   result,                                             expected)
+            ("add",                     two.checked_add(&two),                              Ok(BigNum::from_f64(4.0))),
+            ("add NaN",                 two.checked_add(&BigNum::NAN),                      Err(E::NanOperand)),
+            ("inf - inf",               BigNum::INFINITY.checked_sub(&BigNum::INFINITY),    Err(E::Indeterminate)),
+            ("add infinity",            two.checked_add(&BigNum::INFINITY),                 Ok(BigNum::INFINITY)),
+            ("sub to zero",             two.checked_sub(&two),                              Ok(BigNum::ZERO)),
+            ("mul overflow",            huge.checked_mul(&huge),                            Err(E::Overflow)),
+            ("mul underflow",           tiny.checked_mul(&tiny),                            Err(E::Underflow)),
+            ("0 * inf",                 BigNum::ZERO.checked_mul(&BigNum::INFINITY),        Err(E::Indeterminate)),
+            ("mul zero",                BigNum::ZERO.checked_mul(&huge),                    Ok(BigNum::ZERO)),
+            ("div by zero",             two.checked_div(&BigNum::ZERO),                     Err(E::DivisionByZero)),
+            ("0 / 0",                   BigNum::ZERO.checked_div(&BigNum::ZERO),            Err(E::Indeterminate)),
+            ("inf / inf",               BigNum::INFINITY.checked_div(&BigNum::INFINITY),    Err(E::Indeterminate)),
+            ("div underflow",           tiny.checked_div(&huge),                            Err(E::Underflow)),
+            ("recip zero",              BigNum::ZERO.checked_recip(),                       Err(E::DivisionByZero)),
+            ("powb overflow",           huge.checked_powb(&two),                            Err(E::Overflow)),
+            ("powb negative",           BigNum::NEG_ONE.checked_powf(0.5),                  Err(E::NegativeDomain)),
+            ("powb negative integer",   (-two).checked_powf(2.0),                           Ok(two.powb(two))),
+            ("0 ^ -1",                  BigNum::ZERO.checked_powf(-1.0),                    Err(E::DivisionByZero)),
+            ("inf ^ 0",                 BigNum::INFINITY.checked_powf(0.0),                 Err(E::Indeterminate)),
+            ("1 ^ inf",                 BigNum::ONE.checked_powb(&BigNum::INFINITY),        Err(E::Indeterminate)),
+            ("sqrt",                    BigNum::from_f64(4.0).checked_sqrt(),               Ok(BigNum::from_exponent(4_f64.log10() / 2.0, false))),
+            ("sqrt negative",           (-two).checked_sqrt(),                              Err(E::NegativeDomain)),
+            ("sqrt -0",                 (-BigNum::ZERO).checked_sqrt(),                     Ok(BigNum::ZERO)),
+            ("log10 negative",          (-two).checked_log10(),                             Err(E::NegativeDomain)),
+            ("log10 zero",              BigNum::ZERO.checked_log10(),                       Err(E::DivisionByZero)),
+            ("ln NaN",                  BigNum::NAN.checked_ln(),                           Err(E::NanOperand))
+        ];
+
+        for (name, result, expected) in tests {
+            assert_eq!(result, expected, "Checked operation: {}", name);
+        }
+
+        assert!(two.checked_log10().is_ok());
+        assert_eq!(E::NegativeDomain.to_string(), "operation is undefined for negative numbers");
+    }
+
+    #[test]
+    fn test_gamma() {
+        let sqrt_pi = std::f64::consts::PI.sqrt();
+
+        let tests = vec![
+            // (x,                                  expected log10|Γ(x)|,                       negative)
+            (BigNum::from_f64(5.0),                 24_f64.log10(),                             false),
+            (BigNum::from_f64(0.5),                 sqrt_pi.log10(),                            false),
+            (BigNum::from_f64(1.5),                 (sqrt_pi / 2.0).log10(),                    false),
+            (BigNum::from_f64(-0.5),                (2.0 * sqrt_pi).log10(),                    true),
+            (BigNum::from_f64(-1.5),                (4.0 * sqrt_pi / 3.0).log10(),              false),
+            (BigNum::from_f64(-2.5),                0.9453087204829417_f64.log10(),             true),
+            (BigNum::from_f64(171.0),               7.257415615307994e306_f64.log10(),          false),
+            (BigNum::from_f64(172.0),               309.09377810522045,                         false),
+            (BigNum::from_f64(1001.0),              2567.6046442221323,                         false),
+            (BigNum::from_f64(1000001.0),           5565708.9171867175,                         false)
+        ];
+
+        for (x, expected, negative) in tests {
+            let gamma = x.gamma();
+            assert!(almost_equal(gamma.exponent, expected, expected.abs().max(1.0) * 1e-13), "Gamma check: Γ({}) = {}", x, gamma);
+            assert_eq!(gamma.is_negative, negative, "Gamma sign check: Γ({})", x);
+        }
+
+        // Exact for small integers
+        assert_eq!(BigNum::from_f64(5.0).factorial(), BigNum::from_f64(120.0));
+        assert_eq!(BigNum::from_f64(170.0).factorial(), BigNum::from_f64(7.257415615307994e306));
+        assert!(almost_equal(BigNum::from_f64(0.5).factorial().to_f64().unwrap(), sqrt_pi / 2.0, 1e-14));
+
+        // Huge arguments
+        let huge = BigNum::from_exponent(300.0, false);
+        assert!(almost_equal(huge.gamma().exponent, 2.9956570551809675e302, 1e290));
+        assert!(almost_equal(huge.ln_gamma().exponent, 302.8387077822504, 1e-12));
+        assert!(almost_equal(BigNum::from_exponent(400.0, false).ln_gamma().exponent, 402.96380389462126, 1e-12));
+
+        // Still fits in an f64, but x ln(x) doesn't
+        assert!(almost_equal(BigNum::from_f64(1e306).ln_gamma().exponent, 308.8473202992706, 1e-12));
+        assert!(almost_equal(BigNum::from_f64(1e307).ln_gamma().exponent, 309.8487392588614, 1e-12));
+        assert!(BigNum::from_f64(f64::MAX).ln_gamma().is_finite(), "ln_gamma(f64::MAX) should be finite");
+        assert_eq!(BigNum::from_exponent(400.0, false).gamma(), BigNum::INFINITY);
+        assert!(almost_equal(BigNum::from_f64(-2.5).ln_gamma().to_f64().unwrap(), -0.05624371649767457, 1e-13));
+
+        // Poles and special values
+        assert!(BigNum::from_f64(-1.0).gamma().is_nan());
+        assert!(BigNum::from_f64(-2.0).factorial().is_nan());
+        assert!(BigNum::from_exponent(400.0, true).gamma().is_nan());
+        assert!(BigNum::NAN.gamma().is_nan());
+        assert!(BigNum::NEG_INFINITY.gamma().is_nan());
+        assert_eq!(BigNum::ZERO.gamma(), BigNum::INFINITY);
+        assert_eq!((-BigNum::ZERO).gamma(), BigNum::NEG_INFINITY);
+        assert_eq!(BigNum::INFINITY.gamma(), BigNum::INFINITY);
+        assert_eq!(BigNum::from_f64(-3.0).ln_gamma(), BigNum::INFINITY);
+
+        // Tiny arguments, where Γ(x) = 1/x
+        let tiny_tests = vec![
+            (BigNum::from_exponent(-400.0, false),  400.0,  false),
+            (BigNum::from_exponent(-400.0, true),   400.0,  true),
+            (BigNum::from_exponent(-310.0, false),  310.0,  false),
+            (BigNum::from_exponent(-20.0, true),    20.0,   true)
+        ];
+
+        for (x, expected, negative) in tiny_tests {
+            let gamma = x.gamma();
+            assert!(almost_equal(gamma.exponent, expected, 1e-12), "Gamma check: Γ({}) = {}", x, gamma);
+            assert_eq!(gamma.is_negative, negative, "Gamma sign check: Γ({})", x);
+        }
+
+        assert!(almost_equal(BigNum::from_exponent(-400.0, false).ln_gamma().to_f64().unwrap(), 921.0340371976183, 1e-10));
+        assert!(almost_equal(BigNum::from_exponent(-310.0, true).ln_gamma().to_f64().unwrap(), 713.8013788281542, 1e-10));
+    }
+
+    #[test]
+    fn test_combinatorics() {
+        use crate::bignum::BigNumError as E;
+
+        // Small results are exact
+        let exact_tests = vec![
+            (BigNum::binomial(BigNum::from_f64(5.0), BigNum::from_f64(2.0)),         10.0),
+            (BigNum::binomial(BigNum::from_f64(52.0), BigNum::from_f64(5.0)),        2598960.0),
+            (BigNum::binomial(BigNum::from_f64(10.0), BigNum::from_f64(0.0)),        1.0),
+            (BigNum::binomial(BigNum::from_f64(10.0), BigNum::from_f64(10.0)),       1.0),
+            (BigNum::binomial(BigNum::from_f64(3.0), BigNum::from_f64(5.0)),         0.0),
+            (BigNum::binomial(BigNum::from_f64(1e12), BigNum::from_f64(1.0)),        1e12),
+            (BigNum::permutations(BigNum::from_f64(10.0), BigNum::from_f64(3.0)),    720.0),
+            (BigNum::permutations(BigNum::from_f64(7.0), BigNum::from_f64(7.0)),     5040.0),
+            (BigNum::permutations(BigNum::from_f64(3.0), BigNum::from_f64(4.0)),     0.0),
+            (BigNum::multinomial(&[BigNum::from_f64(2.0), BigNum::from_f64(3.0), BigNum::from_f64(4.0)]), 1260.0),
+            (BigNum::multinomial(&[]),                                               1.0),
+            (BigNum::catalan(BigNum::from_f64(0.0)),                                 1.0),
+            (BigNum::catalan(BigNum::from_f64(10.0)),                                16796.0),
+            (BigNum::catalan(BigNum::from_f64(35.0)),                                3116285494907301262.0)
+        ];
+
+        for (result, expected) in exact_tests {
+            assert_eq!(result, Ok(BigNum::from_f64(expected)), "Exact combinatorics check: expected {}", expected);
+        }
+
+        // Large results, as log10 of the result
+        let log_tests = vec![
+            (BigNum::binomial(BigNum::from_f64(1e12), BigNum::from_f64(3.0)),                  35.221848749615053),
+            (BigNum::binomial(BigNum::from_f64(1e12), BigNum::from_f64(20.0)),                 221.61387538303977),
+            (BigNum::binomial(BigNum::from_f64(200.0), BigNum::from_f64(100.0)),               58.956881330608676),
+            (BigNum::binomial(BigNum::from_f64(1000.0), BigNum::from_f64(500.0)),              299.43182715186374),
+            (BigNum::binomial(BigNum::from_f64(1e12), BigNum::from_f64(1e6)),                  6434290.8656661853),
+            (BigNum::binomial(BigNum::from_f64(1e12), BigNum::from_f64(5e11)),                 301029995657.88314),
+            (BigNum::binomial(BigNum::from_exponent(400.0, false), BigNum::from_f64(2.0)),     799.69897000433602),
+            (BigNum::binomial(BigNum::from_exponent(400.0, false), BigNum::from_f64(1e12)),    388434294481896.85),
+            (BigNum::permutations(BigNum::from_f64(1000.0), BigNum::from_f64(500.0)),          1433.5182356869983),
+            (BigNum::permutations(BigNum::from_f64(1e12), BigNum::from_f64(1e6)),              11999999.782852904),
+            (BigNum::multinomial(&[BigNum::from_f64(1000.0), BigNum::from_f64(2000.0), BigNum::from_f64(3000.0)]), 2631.6855066278773),
+            (BigNum::catalan(BigNum::from_f64(1000.0)),                                        597.31092802732803),
+            (BigNum::catalan(BigNum::from_f64(1e12)),                                          602059991309.71382)
+        ];
+
+        for (result, expected) in log_tests {
+            let result = result.unwrap();
+            assert!(almost_equal(result.exponent, expected, expected * 1e-13), "Log combinatorics check: expected e{}, got {}", expected, result);
+        }
+
+        // Invalid arguments
+        let error_tests = vec![
+            (BigNum::binomial(BigNum::from_f64(-1.0), BigNum::from_f64(2.0)),                  E::NegativeDomain),
+            (BigNum::binomial(BigNum::from_f64(5.0), BigNum::from_f64(-2.0)),                  E::NegativeDomain),
+            (BigNum::binomial(BigNum::from_f64(2.5), BigNum::from_f64(1.0)),                   E::NonInteger),
+            (BigNum::binomial(BigNum::from_f64(5.0), BigNum::from_exponent(-400.0, false)),    E::NonInteger),
+            (BigNum::binomial(BigNum::NAN, BigNum::from_f64(1.0)),                             E::NanOperand),
+            (BigNum::binomial(BigNum::INFINITY, BigNum::from_f64(1.0)),                        E::Overflow),
+            (BigNum::binomial(BigNum::from_exponent(400.0, false), BigNum::from_exponent(350.0, false)), E::Overflow),
+            (BigNum::permutations(BigNum::from_f64(4.0), BigNum::from_f64(0.5)),               E::NonInteger),
+            (BigNum::multinomial(&[BigNum::from_f64(1.0), BigNum::from_f64(-1.0)]),            E::NegativeDomain),
+            (BigNum::catalan(BigNum::from_f64(1.5)),                                           E::NonInteger)
+        ];
+
+        for (result, expected) in error_tests {
+            assert_eq!(result, Err(expected), "Combinatorics error check: expected {}", expected);
+        }
+
+        assert_eq!(BigNum::binomial(BigNum::from_f64(-0.0), BigNum::from_f64(0.0)), Ok(BigNum::ONE));
+    }
+
+    #[test]
+    fn test_lambert_w() {
+        let inv_e = 0.36787944117144233;
+
+        let tests = vec![
+            ("W0(1)",           BigNum::from_f64(1.0).lambert_w0(),                    0.5671432904097839),
+            ("W0(e)",           BigNum::E.lambert_w0(),                                1.0),
+            ("W0(10)",          BigNum::from_f64(10.0).lambert_w0(),                   1.7455280027406994),
+            ("W0(0.001)",       BigNum::from_f64(0.001).lambert_w0(),                  0.0009990014973385309),
+            ("W0(-0.1)",        BigNum::from_f64(-0.1).lambert_w0(),                   -0.11183255915896296),
+            ("W0(-0.3)",        BigNum::from_f64(-0.3).lambert_w0(),                   -0.48940222718021497),
+            ("W0(-1/e)",        BigNum::from_f64(-inv_e).lambert_w0(),                 -1.0),
+            ("W0(1e100)",       BigNum::from_f64(1e100).lambert_w0(),                  224.84310644511850),
+            ("W0(e1000)",       BigNum::from_exponent(1000.0, false).lambert_w0(),     2294.8466716835069),
+            ("W-1(-0.1)",       BigNum::from_f64(-0.1).lambert_wm1(),                  -3.5771520639572972),
+            ("W-1(-0.3)",       BigNum::from_f64(-0.3).lambert_wm1(),                  -1.7813370234216276),
+            ("W-1(-0.36)",      BigNum::from_f64(-0.36).lambert_wm1(),                 -1.2227701339785060),
+            ("W-1(-1/e)",       BigNum::from_f64(-inv_e).lambert_wm1(),                -1.0),
+            ("W-1(-1e-10)",     BigNum::from_f64(-1e-10).lambert_wm1(),                -26.295238819246926),
+            ("W-1(-1e-300)",    BigNum::from_f64(-1e-300).lambert_wm1(),               -697.32277629546016),
+            ("W-1(-e-1000)",    BigNum::from_exponent(-1000.0, true).lambert_wm1(),    -2310.3302387478405)
+        ];
+
+        for (name, result, expected) in tests {
+            let result = result.to_f64().unwrap();
+            assert!(almost_equal(result, expected, expected.abs().max(1e-3) * 1e-12), "Lambert W check: {} = {}, got {}", name, expected, result);
+        }
+
+        // Tiny arguments, where W(x) = x
+        assert_eq!(BigNum::from_exponent(-50.0, false).lambert_w0(), BigNum::from_exponent(-50.0, false));
+        assert_eq!(BigNum::from_exponent(-400.0, true).lambert_w0(), BigNum::from_exponent(-400.0, true));
+
+        // Arguments whose logarithm overflows an f64, in log space
+        let w = BigNum::MAX.lambert_w0();
+        assert!(almost_equal(w.exponent, 308.61693124861621, 1e-12), "Lambert W check: W0(MAX) = e308.6169, got {}", w);
+        let w = BigNum { is_negative: true, exponent: -f64::MAX }.lambert_wm1();
+        assert!(w.is_negative && almost_equal(w.exponent, 308.61693124861621, 1e-12), "Lambert W check: W-1(-1/MAX) = -e308.6169, got {}", w);
+
+        // Both branches invert x e^x
+        for x in [-0.3, -0.05, 0.5, 3.0, 50.0] {
+            let w = BigNum::from_f64(x).lambert_w0().to_f64().unwrap();
+            assert!(almost_equal(w * w.exp(), x, x.abs() * 1e-13), "Lambert W inverse check: W0({})", x);
+        }
+
+        for x in [-0.35, -0.2, -1e-5] {
+            let w = BigNum::from_f64(x).lambert_wm1().to_f64().unwrap();
+            assert!(almost_equal(w * w.exp(), x, x.abs() * 1e-12), "Lambert W inverse check: W-1({})", x);
+        }
+
+        // Outside the domain
+        assert!(BigNum::from_f64(-0.5).lambert_w0().is_nan());
+        assert!(BigNum::from_f64(-0.5).lambert_wm1().is_nan());
+        assert!(BigNum::from_f64(0.5).lambert_wm1().is_nan());
+        assert!(BigNum::NAN.lambert_w0().is_nan());
+        assert!(BigNum::NEG_INFINITY.lambert_w0().is_nan());
+        assert_eq!(BigNum::INFINITY.lambert_w0(), BigNum::INFINITY);
+        assert_eq!(BigNum::ZERO.lambert_w0(), BigNum::ZERO);
+        assert_eq!(BigNum::ZERO.lambert_wm1(), BigNum::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_exponential_functions() {
+        // Results as log10 of the result
+        let exponent_tests = vec![
+            ("exp(1)",          BigNum::from_f64(1.0).exp(),                  0.4342944819032518),
+            ("exp(1e-30)",      BigNum::from_f64(1e-30).exp(),                4.342944819032518e-31),
+            ("exp(1000)",       BigNum::from_f64(1000.0).exp(),               434.2944819032518),
+            ("exp(-1000)",      BigNum::from_f64(-1000.0).exp(),              -434.2944819032518),
+            ("exp(1e300)",      BigNum::from_f64(1e300).exp(),                4.342944819032518e299),
+            ("exp(e308.5)",     BigNum::from_exponent(308.5, false).exp(),    1.3733597380570538e308),
+            ("exp(-e308.5)",    BigNum::from_exponent(308.5, true).exp(),     -1.3733597380570538e308),
+            ("exp2(10)",        BigNum::from_f64(10.0).exp2(),                1024_f64.log10()),
+            ("exp2(-1)",        BigNum::from_f64(-1.0).exp2(),                0.5_f64.log10()),
+            ("exp10(2.5)",      BigNum::from_f64(2.5).exp10(),                2.5),
+            ("exp10(-400)",     BigNum::from_f64(-400.0).exp10(),             -400.0),
+            ("exp10(1e-20)",    BigNum::from_f64(1e-20).exp10(),              1e-20)
+        ];
+
+        for (name, result, expected) in exponent_tests {
+            assert!(!result.is_negative, "Exponential sign check: {}", name);
+            assert!(almost_equal(result.exponent, expected, expected.abs() * 1e-12), "Exponential check: {} = e{:e}, got e{:e}", name, expected, result.exponent);
+        }
+
+        let value_tests = vec![
+            ("exp_m1(1e-5)",     BigNum::from_f64(1e-5).exp_m1(),     1.0000050000166667e-5),
+            ("exp_m1(1)",        BigNum::from_f64(1.0).exp_m1(),      1.718281828459045),
+            ("exp_m1(-0.5)",     BigNum::from_f64(-0.5).exp_m1(),     -0.3934693402873666),
+            ("exp_m1(-50)",      BigNum::from_f64(-50.0).exp_m1(),    -1.0),
+            ("ln_1p(1e-5)",      BigNum::from_f64(1e-5).ln_1p(),      9.99995000033333e-6),
+            ("ln_1p(1)",         BigNum::from_f64(1.0).ln_1p(),       std::f64::consts::LN_2),
+            ("ln_1p(-0.5)",      BigNum::from_f64(-0.5).ln_1p(),      -std::f64::consts::LN_2),
+            ("ln_1p(-0.999)",    BigNum::from_f64(-0.999).ln_1p(),    -6.907755278982137),
+            ("ln_1p(1e100)",     BigNum::from_f64(1e100).ln_1p(),     230.25850929940457)
+        ];
+
+        for (name, result, expected) in value_tests {
+            let result = result.to_f64().unwrap();
+            assert!(almost_equal(result, expected, expected.abs() * 1e-13), "Exponential check: {} = {}, got {}", name, expected, result);
+        }
+
+        // Tiny arguments keep every digit of their exponent
+        assert_eq!(BigNum::from_exponent(-30.0, false).exp_m1(), BigNum::from_exponent(-30.0, false));
+        assert_eq!(BigNum::from_exponent(-30.0, true).exp_m1(), BigNum::from_exponent(-30.0, true));
+        assert_eq!(BigNum::from_exponent(-30.0, false).ln_1p(), BigNum::from_exponent(-30.0, false));
+        assert_eq!(BigNum::from_exponent(-400.0, true).ln_1p(), BigNum::from_exponent(-400.0, true));
+        assert_eq!(BigNum::from_exponent(-400.0, false).exp_m1(), BigNum::from_exponent(-400.0, false));
+
+        // Huge arguments
+        let ln = BigNum::from_exponent(1e308, false).ln_1p();
+        assert!(almost_equal(ln.exponent, 308.36221568869946, 1e-12), "Exponential check: ln_1p(e1e308) = e308.3622, got {}", ln);
+        assert_eq!(BigNum::MAX.exp(), BigNum::INFINITY);
+        assert_eq!(BigNum::MIN.exp(), BigNum::ZERO);
+        assert_eq!(BigNum::from_exponent(400.0, false).exp10(), BigNum::INFINITY);
+        assert_eq!(BigNum::from_exponent(400.0, true).exp_m1(), BigNum::NEG_ONE);
+
+        // Special values
+        assert_eq!(BigNum::ZERO.exp(), BigNum::ONE);
+        assert_eq!(BigNum::INFINITY.exp(), BigNum::INFINITY);
+        assert_eq!(BigNum::NEG_INFINITY.exp(), BigNum::ZERO);
+        assert_eq!(BigNum::INFINITY.ln_1p(), BigNum::INFINITY);
+        assert_eq!(BigNum::NEG_ONE.ln_1p(), BigNum::NEG_INFINITY);
+        assert!(BigNum::from_f64(-2.0).ln_1p().is_nan());
+        assert!(BigNum::NAN.exp().is_nan());
+        assert!(BigNum::NAN.exp_m1().is_nan());
+        assert!(BigNum::NAN.ln_1p().is_nan());
+    }
+
+    #[test]
+    fn test_logarithms() {
+        use std::f64::consts::{LN_2, LOG10_2};
+
+        let tests = vec![
+            ("log10(0.01)",             BigNum::from_f64(0.01).log10(),                             -2.0),
+            ("log10(1000)",             BigNum::from_f64(1000.0).log10(),                           3.0),
+            ("log10(e-400)",            BigNum::from_exponent(-400.0, false).log10(),               -400.0),
+            ("log10(1)",                BigNum::from_f64(1.0).log10(),                              0.0),
+            ("ln(e)",                   BigNum::E.ln(),                                             1.0),
+            ("ln(0.5)",                 BigNum::from_f64(0.5).ln(),                                 -LN_2),
+            ("ln(e-400)",               BigNum::from_exponent(-400.0, false).ln(),                  -921.0340371976183),
+            ("log2(0.25)",              BigNum::from_f64(0.25).log2(),                              -2.0),
+            ("log2(1024)",              BigNum::from_f64(1024.0).log2(),                            10.0),
+            ("log(0.001, 10)",          BigNum::from_f64(0.001).log(10.0),                          -3.0),
+            ("log(8, 2)",               BigNum::from_f64(8.0).log(2.0),                             3.0),
+            ("log(8, 0.5)",             BigNum::from_f64(8.0).log(0.5),                             -3.0),
+            ("log_b(1e-200, 1e100)",    BigNum::from_f64(1e-200).log_b(BigNum::from_f64(1e100)),    -2.0),
+            ("log_b(e-1000, e500)",     BigNum::from_exponent(-1000.0, false).log_b(BigNum::from_exponent(500.0, false)), -2.0),
+            ("log_b(8, 2)",             BigNum::from_f64(8.0).log_b(BigNum::from_f64(2.0)),         3.0),
+            ("log_b(100, 0.1)",         BigNum::from_f64(100.0).log_b(BigNum::from_f64(0.1)),       -2.0),
+            ("abs_log10(-0.01)",        BigNum::from_f64(-0.01).abs_log10(),                        -2.0)
+        ];
+
+        for (name, result, expected) in tests {
+            let result = result.to_f64().unwrap();
+            assert!(almost_equal(result, expected, expected.abs().max(1.0) * 1e-14), "Logarithm check: {} = {}, got {}", name, expected, result);
+        }
+
+        // Results too large for an f64
+        let huge = BigNum::from_exponent(1e300, false).log_b(BigNum::from_exponent(1e100, false));
+        assert!(almost_equal(huge.exponent, 200.0, 1e-12), "Logarithm check: log_b(e1e300, e1e100) = e200, got {}", huge);
+        let huge = BigNum::MAX.ln();
+        assert!(almost_equal(huge.exponent, f64::MAX.log10() + std::f64::consts::LN_10.log10(), 1e-12), "Logarithm check: ln(MAX), got {}", huge);
+        let huge = BigNum::MAX.log2();
+        assert!(almost_equal(huge.exponent, f64::MAX.log10() - LOG10_2.log10(), 1e-12), "Logarithm check: log2(MAX), got {}", huge);
+        assert!(BigNum { is_negative: false, exponent: -f64::MAX }.ln().is_negative);
+        let huge = BigNum::from_exponent(1e308, false).log(0.5);
+        assert!(huge.is_negative && almost_equal(huge.exponent, 308.0 - LOG10_2.log10(), 1e-12), "Logarithm check: log(e1e308, 0.5), got {}", huge);
+        let huge = BigNum::from_exponent(-1e308, false).log(0.5);
+        assert!(!huge.is_negative && almost_equal(huge.exponent, 308.0 - LOG10_2.log10(), 1e-12), "Logarithm check: log(e-1e308, 0.5), got {}", huge);
+
+        // There's no logarithm in base 1
+        assert!(BigNum::from_f64(5.0).log(1.0).is_nan(), "Logarithm check: log(5, 1) should be NaN");
+        assert!(BigNum::from_f64(-5.0).abs_log(1.0).is_nan(), "Logarithm check: abs_log(-5, 1) should be NaN");
+        assert!(BigNum::from_f64(5.0).log_b(BigNum::ONE).is_nan(), "Logarithm check: log_b(5, 1) should be NaN");
+        assert!(BigNum::from_f64(5.0).abs_log_b(-BigNum::ONE).is_nan(), "Logarithm check: abs_log_b(5, -1) should be NaN");
+
+        // The checked versions agree
+        assert_eq!(BigNum::from_f64(0.01).checked_log10(), Ok(BigNum::from_f64(0.01).log10()));
+        assert!(BigNum::from_f64(0.01).checked_log10().unwrap().is_negative);
+
+        // Special values
+        assert_eq!(BigNum::ZERO.log10(), BigNum::NEG_INFINITY);
+        assert_eq!(BigNum::ZERO.ln(), BigNum::NEG_INFINITY);
+        assert_eq!(BigNum::INFINITY.log_b(BigNum::from_f64(10.0)), BigNum::INFINITY);
+        assert!(BigNum::from_f64(-1.0).log10().is_nan());
+        assert!(BigNum::from_f64(-1.0).ln().is_nan());
+        assert!(BigNum::from_f64(-1.0).log_b(BigNum::from_f64(10.0)).is_nan());
+        assert!(BigNum::from_f64(2.0).log_b(BigNum::from_f64(-2.0)).is_nan());
+        assert!(BigNum::NAN.log_b(BigNum::from_f64(10.0)).is_nan());
+        assert!(BigNum::from_f64(2.0).log_b(BigNum::NAN).is_nan());
+    }
 }
\ No newline at end of file
@@ -4,12 +4,143 @@ pub mod bignum {
     pub mod operations;
     pub mod constants;
     pub mod misc;
+    pub mod layered;
+    pub mod hyperoperations;
+    pub mod hybrid;
+    pub mod errors;
+    pub mod suffixes;
+    pub mod notation;
+    pub mod packed;
+    pub mod sortable;
+    pub mod binary;
+    pub mod savecodec;
+    pub mod json;
+    pub mod accumulator;
+    pub mod interval;
+    pub mod checked;
+    pub mod special;
+    pub mod combinatorics;
 
     mod tests;
 
+    pub use notation::Notation;
+
     #[derive(Debug, PartialEq, Copy, Clone)]
     pub struct BigNum {
         pub is_negative: bool,
         pub exponent: f64
     }
+
+    // Layer 0 means the same as BigNum: magnitude = log10(|x|).
+    // Each layer above that puts the magnitude one power of ten higher up the tower,
+    // e.g. layer 2 with magnitude 400 is 10^10^10^400.
+    // On layers above 0, a negative magnitude means the reciprocal, e.g. layer 1 with magnitude -400 is 10^-10^400.
+    #[derive(Debug, PartialEq, Copy, Clone)]
+    pub struct LayeredNum {
+        pub is_negative: bool,
+        pub layer: u32,
+        pub magnitude: f64
+    }
+
+    // Integers below 2^53 are stored exactly, so they don't pick up the rounding errors of the log representation.
+    // Everything else falls back to a BigNum.
+    #[derive(Debug, PartialEq, Copy, Clone)]
+    pub enum HybridNum {
+        Exact(i64),
+        Approximate(BigNum)
+    }
+
+    // A BigNum in 8 bytes instead of 16, for large arrays.
+    // The sign takes the place of the lowest bit of the exponent, so the exponent loses one bit of precision.
+    #[derive(Copy, Clone)]
+    #[repr(transparent)]
+    pub struct PackedBigNum(u64);
+
+    // A running total that doesn't lose small additions.
+    // Amounts too small to change `value` are summed in `pending` (in units of 10^scale) until they add up to something.
+    #[derive(Debug, PartialEq, Copy, Clone)]
+    pub struct BigAccumulator {
+        value: BigNum,
+        pending: f64,
+        compensation: f64,
+        scale: f64
+    }
+
+    // A range that is guaranteed to hold the exact result, since every operation rounds lo down and hi up
+    #[derive(Debug, PartialEq, Copy, Clone)]
+    pub struct BigInterval {
+        pub lo: BigNum,
+        pub hi: BigNum
+    }
+
+    // Result of comparing intervals, which can overlap
+    #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+    pub enum Certainty {
+        Definitely,
+        Maybe,
+        DefinitelyNot
+    }
+
+    // Positions are byte offsets into the parsed string
+    #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+    pub enum ParseBigNumError {
+        Empty,
+        InvalidSign { position: usize },
+        InvalidMantissa { position: usize },
+        InvalidExponent { position: usize },
+        TrailingCharacters { position: usize },
+        InvalidSuffix { position: usize }
+    }
+
+    // Positions are byte offsets into the decoded bytes
+    #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+    pub enum BinaryDecodeError {
+        InvalidMagic,
+        UnsupportedVersion { version: u8 },
+        UnexpectedEnd { position: usize },
+        InvalidSign { position: usize, value: u8 },
+        TrailingBytes { position: usize }
+    }
+
+    // Positions are byte offsets into the JSON text
+    #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+    pub enum JsonError {
+        UnexpectedEnd,
+        UnexpectedCharacter { position: usize },
+        InvalidEscape { position: usize },
+        InvalidNumber { position: usize, error: ParseBigNumError },
+        TrailingCharacters { position: usize }
+    }
+
+    // A value that can be stored in a save string
+    #[derive(Debug, PartialEq, Copy, Clone)]
+    pub enum SaveValue {
+        Number(BigNum),
+        Integer(i64),
+        Boolean(bool)
+    }
+
+    // Positions are offsets into the text for `InvalidCharacter`, and into the decoded bytes otherwise
+    #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+    pub enum SaveDecodeError {
+        InvalidCharacter { position: usize },
+        InvalidLength,
+        ChecksumMismatch { expected: u32, found: u32 },
+        UnsupportedVersion { version: u8 },
+        UnknownTag { position: usize, tag: u8 },
+        UnexpectedEnd { position: usize },
+        IntegerOverflow { position: usize }
+    }
+
+    // Why a checked operation had no finite, well-defined result
+    #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+    pub enum BigNumError {
+        Overflow,
+        Underflow,
+        NegativeDomain,
+        NanOperand,
+        Indeterminate,
+        DivisionByZero,
+        NonInteger
+    }
 }
\ No newline at end of file